@@ -2,7 +2,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use image_swizzle::{rgba_to_bgra, rgba_to_bgra_inplace};
 
 fn generate_xxxx_image(width: usize, height: usize, x1: u8, x2: u8, x3: u8, x4: u8) -> Vec<u8> {
-    assert!((width * height * 4) % 4 == 0);
+    assert!((width * height * 4).is_multiple_of(4));
     let mut xxxx = Vec::with_capacity(width * height);
     for _ in 0..width * height {
         xxxx.push(x1);
@@ -43,10 +43,8 @@ macro_rules! impl_tests {
         #[test]
         fn test_rgba32_to_rgba_inplace() {
             let (width, height) = (10, 10);
-            #[cfg(target_endian = "little")]
-            let mut rgba_img = generate_xxxx_image(width, height, 255, 222, 111, 0);
-            #[cfg(target_endian = "big")]
-            todo!();
+            let [x1, x2, x3, x4] = u32::from_be_bytes([0, 111, 222, 255]).to_ne_bytes();
+            let mut rgba_img = generate_xxxx_image(width, height, x1, x2, x3, x4);
             let correct = generate_xxxx_image(width, height, 0, 111, 222, 255);
             rgba32_to_rgba_inplace(&mut rgba_img);
             assert_eq!(rgba_img, correct);
@@ -55,15 +53,35 @@ macro_rules! impl_tests {
         #[test]
         fn test_rgba32_to_bgra_inplace() {
             let (width, height) = (10, 10);
-            #[cfg(target_endian = "little")]
-            let mut rgba_img = generate_xxxx_image(width, height, 255, 222, 111, 0);
-            #[cfg(target_endian = "big")]
-            todo!();
+            let [x1, x2, x3, x4] = u32::from_be_bytes([0, 111, 222, 255]).to_ne_bytes();
+            let mut rgba_img = generate_xxxx_image(width, height, x1, x2, x3, x4);
             let correct = generate_xxxx_image(width, height, 222, 111, 0, 255);
             rgba32_to_bgra_inplace(&mut rgba_img);
             assert_eq!(rgba_img, correct);
         }
 
+        #[test]
+        fn test_rgba32_to_rgba() {
+            let (width, height) = (10, 10);
+            let [x1, x2, x3, x4] = u32::from_be_bytes([0, 111, 222, 255]).to_ne_bytes();
+            let rgba32_img = generate_xxxx_image(width, height, x1, x2, x3, x4);
+            let correct = generate_xxxx_image(width, height, 0, 111, 222, 255);
+            let mut rgba = vec![0; width * height * 4];
+            rgba32_to_rgba(&rgba32_img, &mut rgba);
+            assert_eq!(rgba, correct);
+        }
+
+        #[test]
+        fn test_rgba32_to_bgra() {
+            let (width, height) = (10, 10);
+            let [x1, x2, x3, x4] = u32::from_be_bytes([0, 111, 222, 255]).to_ne_bytes();
+            let rgba32_img = generate_xxxx_image(width, height, x1, x2, x3, x4);
+            let correct = generate_xxxx_image(width, height, 222, 111, 0, 255);
+            let mut bgra = vec![0; width * height * 4];
+            rgba32_to_bgra(&rgba32_img, &mut bgra);
+            assert_eq!(bgra, correct);
+        }
+
         #[test]
         fn test_rgba_to_bgra() {
             let (width, height) = (1920, 1080);
@@ -180,6 +198,416 @@ macro_rules! impl_tests {
             assert_eq!(rgbx, correct_rgbx);
         }
 
+        #[test]
+        fn test_swizzle4() {
+            let (width, height) = (33, 7);
+            let src = generate_xxxx_image(width, height, 1, 2, 3, 4);
+            for (order, expected) in [
+                ([0, 1, 2, 3], [1, 2, 3, 4]),
+                ([2, 1, 0, 3], [3, 2, 1, 4]),
+                ([3, 2, 1, 0], [4, 3, 2, 1]),
+                ([1, 2, 3, 0], [2, 3, 4, 1]),
+                ([3, 0, 1, 2], [4, 1, 2, 3]),
+                ([0, 0, 0, 3], [1, 1, 1, 4]),
+                ([2, 2, 2, 2], [3, 3, 3, 3]),
+            ] {
+                let correct = generate_xxxx_image(
+                    width, height, expected[0], expected[1], expected[2], expected[3],
+                );
+                let mut dst = vec![0; width * height * 4];
+                swizzle4(&src, &mut dst, order);
+                assert_eq!(dst, correct);
+
+                let mut inplace = src.clone();
+                swizzle4_inplace(&mut inplace, order);
+                assert_eq!(inplace, correct);
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_swizzle4_invalid_order() {
+            let src = generate_xxxx_image(4, 4, 1, 2, 3, 4);
+            let mut dst = vec![0; 4 * 4 * 4];
+            swizzle4(&src, &mut dst, [0, 1, 2, 4]);
+        }
+
+        #[test]
+        fn test_fill_lane() {
+            let (width, height) = (33, 7);
+            let src = generate_xxxx_image(width, height, 1, 2, 3, 4);
+            for (lane, value, expected) in [
+                (3, 255, [1, 2, 3, 255]),
+                (3, 0, [1, 2, 3, 0]),
+                (0, 128, [128, 2, 3, 4]),
+                (2, 7, [1, 2, 7, 4]),
+            ] {
+                let correct = generate_xxxx_image(
+                    width,
+                    height,
+                    expected[0],
+                    expected[1],
+                    expected[2],
+                    expected[3],
+                );
+                let mut dst = vec![0; width * height * 4];
+                fill_lane(&src, &mut dst, lane, value);
+                assert_eq!(dst, correct);
+
+                let mut inplace = src.clone();
+                fill_lane_inplace(&mut inplace, lane, value);
+                assert_eq!(inplace, correct);
+            }
+        }
+
+        #[test]
+        fn test_swizzle4_fill() {
+            let (width, height) = (33, 7);
+            let src = generate_xxxx_image(width, height, 1, 2, 3, 4);
+            for (order, lane, value, expected) in [
+                ([2, 1, 0, 3], 3, 0, [3, 2, 1, 0]),
+                ([1, 2, 3, 0], 0, 128, [2, 3, 4, 128]),
+                ([3, 2, 1, 0], 0, 255, [4, 3, 2, 255]),
+                ([0, 0, 1, 2], 0, 9, [9, 9, 2, 3]),
+            ] {
+                let correct = generate_xxxx_image(
+                    width,
+                    height,
+                    expected[0],
+                    expected[1],
+                    expected[2],
+                    expected[3],
+                );
+                let mut dst = vec![0; width * height * 4];
+                swizzle4_fill(&src, &mut dst, order, lane, value);
+                assert_eq!(dst, correct);
+
+                let mut inplace = src.clone();
+                swizzle4_fill_inplace(&mut inplace, order, lane, value);
+                assert_eq!(inplace, correct);
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_fill_lane_invalid_lane() {
+            let mut src = generate_xxxx_image(4, 4, 1, 2, 3, 4);
+            fill_lane_inplace(&mut src, 4, 0);
+        }
+
+        #[test]
+        fn test_swizzle_const_order() {
+            let (width, height) = (33, 7);
+            let src = generate_xxxx_image(width, height, 1, 2, 3, 4);
+
+            let mut dst = vec![0; width * height * 4];
+            swizzle::<3, 0, 1, 2>(&src, &mut dst);
+            assert_eq!(dst, generate_xxxx_image(width, height, 4, 1, 2, 3));
+
+            let mut dst = vec![0; width * height * 4];
+            swizzle::<0, 0, 0, 3>(&src, &mut dst);
+            assert_eq!(dst, generate_xxxx_image(width, height, 1, 1, 1, 4));
+
+            let mut inplace = src.clone();
+            swizzle_inplace::<2, 1, 0, 3>(&mut inplace);
+            assert_eq!(inplace, generate_xxxx_image(width, height, 3, 2, 1, 4));
+
+            let mut inplace = src.clone();
+            swizzle_inplace::<3, 2, 1, 0>(&mut inplace);
+            assert_eq!(inplace, generate_xxxx_image(width, height, 4, 3, 2, 1));
+        }
+
+        #[test]
+        fn test_conversion_matrix() {
+            use crate::common::check_conversions;
+
+            crate::common::for_each_conversion!(check_conversions);
+        }
+
+        #[test]
+        fn test_expansion_matrix() {
+            use crate::common::check_expansions;
+
+            crate::common::for_each_expansion!(check_expansions);
+        }
+
+        #[test]
+        fn test_rgb24_to_rgba_lengths() {
+            for pixels in [0, 1, 3, 4, 5, 8, 37, 1920] {
+                let src = (0..pixels * 3).map(|i| i as u8).collect::<Vec<u8>>();
+                let mut dst = vec![0; pixels * 4];
+                rgb24_to_rgba(&src, &mut dst, 128);
+                let expected = src
+                    .chunks_exact(3)
+                    .flat_map(|px| [px[0], px[1], px[2], 128])
+                    .collect::<Vec<u8>>();
+                assert_eq!(dst, expected);
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_rgb24_to_rgba_length() {
+            rgb24_to_rgba(&[0; 6], &mut [0; 4], 255);
+        }
+
+        #[test]
+        fn test_rgb24_to_bgr24() {
+            for pixels in [0, 1, 15, 16, 17, 33, 1920 * 3 + 7] {
+                let src = (0..pixels * 3).map(|i| i as u8).collect::<Vec<u8>>();
+                let expected = src
+                    .chunks_exact(3)
+                    .flat_map(|px| [px[2], px[1], px[0]])
+                    .collect::<Vec<u8>>();
+
+                let mut dst = vec![0; src.len()];
+                rgb24_to_bgr24(&src, &mut dst);
+                assert_eq!(dst, expected);
+                bgr24_to_rgb24(&expected, &mut dst);
+                assert_eq!(dst, src);
+
+                let mut inplace = src.clone();
+                rgb24_to_bgr24_inplace(&mut inplace);
+                assert_eq!(inplace, expected);
+                bgr24_to_rgb24_inplace(&mut inplace);
+                assert_eq!(inplace, src);
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_rgb24_to_bgr24_length() {
+            rgb24_to_bgr24_inplace(&mut [0; 8]);
+        }
+
+        #[test]
+        fn test_rgba16_conversions() {
+            use crate::common::check_wide_conversions;
+
+            crate::common::for_each_wide_conversion!(check_wide_conversions {
+                rgba16, u16, u16::MAX, (crate::ByteOrder::NATIVE);
+            });
+        }
+
+        #[test]
+        fn test_rgbaf32_conversions() {
+            use crate::common::check_wide_conversions;
+
+            crate::common::for_each_wide_conversion!(check_wide_conversions {
+                rgbaf32, f32, 1.0, ();
+            });
+        }
+
+        #[test]
+        fn test_rgba16_from_u8() {
+            let src: Vec<u8> = (0..=255).cycle().take(4 * 37).collect();
+            let mut dst = vec![0; src.len()];
+            rgba16::from_u8(&src, &mut dst, [2, 1, 0, 3]);
+            for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact(4)) {
+                assert_eq!(d, [s[2], s[1], s[0], s[3]].map(|c| c as u16 * 0x0101));
+            }
+
+            let mut roundtrip = vec![0; src.len()];
+            rgba16::to_u8(&dst, &mut roundtrip, [2, 1, 0, 3], crate::ByteOrder::NATIVE);
+            assert_eq!(roundtrip, src);
+        }
+
+        #[test]
+        fn test_rgba16_to_u8_rounding() {
+            let src: Vec<u16> = (0..=u16::MAX).collect();
+            let mut dst = vec![0; src.len()];
+            rgba16::to_u8(&src, &mut dst, [3, 2, 1, 0], crate::ByteOrder::NATIVE);
+            for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact(4)) {
+                let expected = [s[3], s[2], s[1], s[0]]
+                    .map(|c| (c as f64 / 257.0).round() as u8);
+                assert_eq!(d, expected);
+            }
+
+            let be: Vec<u16> = src.iter().map(|c| c.to_be()).collect();
+            let mut from_be = vec![0; src.len()];
+            rgba16::to_u8(&be, &mut from_be, [3, 2, 1, 0], crate::ByteOrder::Big);
+            assert_eq!(from_be, dst);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_rgba16_to_u8_length() {
+            rgba16::to_u8(&[0; 8], &mut [0; 4], [0, 1, 2, 3], crate::ByteOrder::NATIVE);
+        }
+
+        #[test]
+        fn test_rgb565_unpack() {
+            use crate::ByteOrder;
+
+            let red = 0xf800u16.to_le_bytes().repeat(37);
+            let mut dst = vec![0; red.len() * 2];
+            rgb565::rgb565_to_rgba(&red, &mut dst, ByteOrder::Little);
+            assert_eq!(dst, [255, 0, 0, 255].repeat(37));
+            rgb565::rgb565_to_bgra(&red, &mut dst, ByteOrder::Little);
+            assert_eq!(dst, [0, 0, 255, 255].repeat(37));
+            rgb565::bgr565_to_rgba(&red, &mut dst, ByteOrder::Little);
+            assert_eq!(dst, [0, 0, 255, 255].repeat(37));
+            rgb565::bgr565_to_bgra(&red, &mut dst, ByteOrder::Little);
+            assert_eq!(dst, [255, 0, 0, 255].repeat(37));
+
+            // Every pixel, the low bits of the channels repeat their high bits.
+            let pixels: Vec<u16> = (0..=u16::MAX).collect();
+            let expected: Vec<u8> = pixels
+                .iter()
+                .flat_map(|px| {
+                    let (r, g, b) = (px >> 11, px >> 5 & 0x3f, px & 0x1f);
+                    [r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2, 255].map(|c| c as u8)
+                })
+                .collect();
+            let mut dst = vec![0; pixels.len() * 4];
+            for (byte_order, to_bytes) in [
+                (ByteOrder::Little, u16::to_le_bytes as fn(u16) -> [u8; 2]),
+                (ByteOrder::Big, u16::to_be_bytes),
+            ] {
+                let src: Vec<u8> = pixels.iter().flat_map(|&px| to_bytes(px)).collect();
+                rgb565::rgb565_to_rgba(&src, &mut dst, byte_order);
+                assert_eq!(dst, expected, "{byte_order:?}");
+            }
+        }
+
+        #[test]
+        fn test_rgb565_pack() {
+            use crate::ByteOrder;
+
+            let mut dst = vec![0; 2 * 37];
+            rgb565::rgba_to_rgb565(&[255, 0, 0, 255].repeat(37), &mut dst, ByteOrder::Little);
+            assert_eq!(dst, 0xf800u16.to_le_bytes().repeat(37));
+            rgb565::bgra_to_rgb565(&[255, 0, 0, 255].repeat(37), &mut dst, ByteOrder::Big);
+            assert_eq!(dst, 0x001fu16.to_be_bytes().repeat(37));
+            rgb565::rgba_to_bgr565(&[255, 0, 0, 255].repeat(37), &mut dst, ByteOrder::Big);
+            assert_eq!(dst, 0x001fu16.to_be_bytes().repeat(37));
+            rgb565::bgra_to_bgr565(&[255, 0, 0, 255].repeat(37), &mut dst, ByteOrder::Little);
+            assert_eq!(dst, 0xf800u16.to_le_bytes().repeat(37));
+
+            // Every channel value is rounded to the nearest 5 or 6 bit value.
+            let src: Vec<u8> = (0..=255).flat_map(|c| [c, 255 - c, c, 0]).collect();
+            let mut dst = vec![0; src.len() / 2];
+            rgb565::rgba_to_rgb565(&src, &mut dst, ByteOrder::Big);
+            for (px, packed) in src.chunks_exact(4).zip(dst.chunks_exact(2)) {
+                let round = |c: u8, max: f64| (c as f64 * max / 255.0).round() as u16;
+                let expected =
+                    round(px[0], 31.0) << 11 | round(px[1], 63.0) << 5 | round(px[2], 31.0);
+                assert_eq!(packed, expected.to_be_bytes(), "{px:?}");
+            }
+
+            // Packing the unpacked pixels gives back every pixel.
+            let pixels: Vec<u8> = (0..=u16::MAX).flat_map(u16::to_le_bytes).collect();
+            let mut unpacked = vec![0; pixels.len() * 2];
+            rgb565::bgr565_to_bgra(&pixels, &mut unpacked, ByteOrder::Little);
+            let mut packed = vec![0; pixels.len()];
+            rgb565::bgra_to_bgr565(&unpacked, &mut packed, ByteOrder::Little);
+            assert_eq!(packed, pixels);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_rgb565_to_rgba_length() {
+            rgb565::rgb565_to_rgba(&[0; 4], &mut [0; 4], crate::ByteOrder::Little);
+        }
+
+        #[test]
+        fn test_rgbaf32_from_u8() {
+            let src: Vec<u8> = (0..=255).cycle().take(4 * 37).collect();
+            let mut dst = vec![0.0; src.len()];
+            rgbaf32::from_u8(&src, &mut dst, [2, 1, 0, 3]);
+            for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact(4)) {
+                let expected = [s[2], s[1], s[0], s[3]].map(|c| c as f32 / 255.0);
+                assert_eq!(d, expected);
+            }
+
+            let (mean, std) = ([0.485, 0.456, 0.406, 0.0], [0.229, 0.224, 0.225, 1.0]);
+            rgbaf32::from_u8_normalized(&src, &mut dst, [2, 1, 0, 3], mean, std);
+            for (s, d) in src.chunks_exact(4).zip(dst.chunks_exact(4)) {
+                let rgba = [s[2], s[1], s[0], s[3]];
+                let expected: [f32; 4] =
+                    std::array::from_fn(|i| (rgba[i] as f32 / 255.0 - mean[i]) / std[i]);
+                assert_eq!(d, expected);
+            }
+        }
+
+        #[test]
+        fn test_rgbaf32_to_u8() {
+            let src: Vec<u8> = (0..=255).cycle().take(4 * 37).collect();
+            let mut floats = vec![0.0; src.len()];
+            rgbaf32::from_u8(&src, &mut floats, [3, 2, 1, 0]);
+            let mut dst = vec![0; src.len()];
+            rgbaf32::to_u8(&floats, &mut dst, [3, 2, 1, 0]);
+            assert_eq!(dst, src);
+
+            let src = [-1.0, 2.0, f32::NAN, 0.5].repeat(37);
+            let mut dst = vec![0; src.len()];
+            rgbaf32::to_u8(&src, &mut dst, [0, 1, 2, 3]);
+            assert_eq!(dst, [0, 255, 0, 128].repeat(37));
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_rgbaf32_to_u8_invalid_order() {
+            rgbaf32::to_u8(&[0.0; 4], &mut [0; 4], [0, 1, 2, 4]);
+        }
+
+        #[test]
+        fn test_rgbaf16_conversions() {
+            use crate::common::check_wide_conversions;
+
+            crate::common::for_each_wide_conversion!(check_wide_conversions {
+                rgbaf16, u16, crate::rgbaf16::ONE, ();
+            });
+        }
+
+        #[test]
+        fn test_rgba16_swap_bytes() {
+            let be = [0x0102u16, 0x0304, 0x0506, 0x0708]
+                .map(u16::to_be)
+                .repeat(37);
+            let mut dst = vec![0; be.len()];
+            rgba16::rgba_to_bgra(&be, &mut dst, crate::ByteOrder::Big);
+            assert_eq!(dst, [0x0506, 0x0304, 0x0102, 0x0708].repeat(37));
+
+            let mut inplace = [0x0102u16, 0x0304, 0x0506, 0].map(u16::to_be).repeat(37);
+            rgba16::rgb0_to_rgbx_inplace(&mut inplace, crate::ByteOrder::Big);
+            assert_eq!(inplace, [0x0102, 0x0304, 0x0506, u16::MAX].repeat(37));
+
+            let mut inplace = be.clone();
+            rgba16::swap_bytes_inplace(&mut inplace);
+            rgba16::swap_bytes(&inplace.clone(), &mut inplace);
+            assert_eq!(inplace, be);
+            rgba16::swap_bytes(&be, &mut dst);
+            assert_eq!(dst, be.iter().map(|c| c.swap_bytes()).collect::<Vec<u16>>());
+        }
+
+        #[test]
+        fn test_packing_matrix() {
+            use crate::common::check_packings;
+
+            crate::common::for_each_packing!(check_packings);
+        }
+
+        #[test]
+        fn test_bgra_to_rgb24_lengths() {
+            for pixels in [0, 1, 3, 4, 5, 8, 37, 1920] {
+                let src = (0..pixels * 4).map(|i| i as u8).collect::<Vec<u8>>();
+                let mut dst = vec![0; pixels * 3];
+                bgra_to_rgb24(&src, &mut dst);
+                let expected = src
+                    .chunks_exact(4)
+                    .flat_map(|px| [px[2], px[1], px[0]])
+                    .collect::<Vec<u8>>();
+                assert_eq!(dst, expected);
+            }
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_panic_rgba_to_rgb24_length() {
+            rgba_to_rgb24(&[0; 8], &mut [0; 8]);
+        }
+
         #[test]
         fn test_argb_to_rgba_inplace() {
             let (width, height) = (1920, 1080);
@@ -189,8 +617,678 @@ macro_rules! impl_tests {
             argb_to_rgba_inplace(&mut argb);
             assert_eq!(argb, correct_rgba);
         }
+
+        #[test]
+        fn test_argb_to_rgba() {
+            let (width, height) = (1920, 1080);
+            let (r, g, b, a) = (1, 2, 3, 4);
+            let argb = generate_xxxx_image(width, height, a, r, g, b);
+            let correct_rgba = generate_xxxx_image(width, height, r, g, b, a);
+            let mut rgba = vec![0; width * height * 4];
+            argb_to_rgba(&argb, &mut rgba);
+            assert_eq!(rgba, correct_rgba);
+        }
     }
 }
 
 #[allow(unused_imports)]
 pub(crate) use impl_tests;
+
+/// Invokes `$m` with every conversion between the 4 channel formats that is not written out by
+/// hand. Every entry is `name, inplace_name, Src => Dst, [order], lane;` where output channel
+/// `i` is taken from input channel `order[i]` after the optional input `lane` has been set to
+/// 255, so the padding of `*0` outputs is written like the padding of `*x` outputs.
+///
+/// `for_each_conversion!(@variants $m)` additionally passes the names of the fallible and
+/// strided versions as `=> try_name, try_inplace_name, strided_name, strided_inplace_name`
+/// at the end of every entry.
+#[rustfmt::skip]
+macro_rules! for_each_conversion {
+    ($m:ident) => {
+        $crate::common::for_each_conversion!(@table @strip $m);
+    };
+    (@variants $m:ident) => {
+        $crate::common::for_each_conversion!(@table $m);
+    };
+    (@strip $m:ident; $($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)? => $($variant:ident),*;)*) => {
+        $m! { $($name, $inplace, $src => $dst, [$($order),*] $(, $lane)?;)* }
+    };
+    ($m:ident; $($entries:tt)*) => {
+        $m! { $($entries)* }
+    };
+    (@table $($m:tt)+) => {
+        $crate::common::for_each_conversion! {
+            $($m)+;
+            rgba_to_argb, rgba_to_argb_inplace, Rgba => Argb, [3, 0, 1, 2]
+                => try_rgba_to_argb, try_rgba_to_argb_inplace,
+                rgba_to_argb_strided, rgba_to_argb_inplace_strided;
+            rgba_to_abgr, rgba_to_abgr_inplace, Rgba => Abgr, [3, 2, 1, 0]
+                => try_rgba_to_abgr, try_rgba_to_abgr_inplace,
+                rgba_to_abgr_strided, rgba_to_abgr_inplace_strided;
+            rgba_to_rgbx, rgba_to_rgbx_inplace, Rgba => Rgbx, [0, 1, 2, 3], 3
+                => try_rgba_to_rgbx, try_rgba_to_rgbx_inplace,
+                rgba_to_rgbx_strided, rgba_to_rgbx_inplace_strided;
+            rgba_to_bgrx, rgba_to_bgrx_inplace, Rgba => Bgrx, [2, 1, 0, 3], 3
+                => try_rgba_to_bgrx, try_rgba_to_bgrx_inplace,
+                rgba_to_bgrx_strided, rgba_to_bgrx_inplace_strided;
+            rgba_to_xrgb, rgba_to_xrgb_inplace, Rgba => Xrgb, [3, 0, 1, 2], 3
+                => try_rgba_to_xrgb, try_rgba_to_xrgb_inplace,
+                rgba_to_xrgb_strided, rgba_to_xrgb_inplace_strided;
+            rgba_to_xbgr, rgba_to_xbgr_inplace, Rgba => Xbgr, [3, 2, 1, 0], 3
+                => try_rgba_to_xbgr, try_rgba_to_xbgr_inplace,
+                rgba_to_xbgr_strided, rgba_to_xbgr_inplace_strided;
+            rgba_to_rgb0, rgba_to_rgb0_inplace, Rgba => Rgb0, [0, 1, 2, 3], 3
+                => try_rgba_to_rgb0, try_rgba_to_rgb0_inplace,
+                rgba_to_rgb0_strided, rgba_to_rgb0_inplace_strided;
+            rgba_to_bgr0, rgba_to_bgr0_inplace, Rgba => Bgr0, [2, 1, 0, 3], 3
+                => try_rgba_to_bgr0, try_rgba_to_bgr0_inplace,
+                rgba_to_bgr0_strided, rgba_to_bgr0_inplace_strided;
+            bgra_to_argb, bgra_to_argb_inplace, Bgra => Argb, [3, 2, 1, 0]
+                => try_bgra_to_argb, try_bgra_to_argb_inplace,
+                bgra_to_argb_strided, bgra_to_argb_inplace_strided;
+            bgra_to_abgr, bgra_to_abgr_inplace, Bgra => Abgr, [3, 0, 1, 2]
+                => try_bgra_to_abgr, try_bgra_to_abgr_inplace,
+                bgra_to_abgr_strided, bgra_to_abgr_inplace_strided;
+            bgra_to_rgbx, bgra_to_rgbx_inplace, Bgra => Rgbx, [2, 1, 0, 3], 3
+                => try_bgra_to_rgbx, try_bgra_to_rgbx_inplace,
+                bgra_to_rgbx_strided, bgra_to_rgbx_inplace_strided;
+            bgra_to_bgrx, bgra_to_bgrx_inplace, Bgra => Bgrx, [0, 1, 2, 3], 3
+                => try_bgra_to_bgrx, try_bgra_to_bgrx_inplace,
+                bgra_to_bgrx_strided, bgra_to_bgrx_inplace_strided;
+            bgra_to_xrgb, bgra_to_xrgb_inplace, Bgra => Xrgb, [3, 2, 1, 0], 3
+                => try_bgra_to_xrgb, try_bgra_to_xrgb_inplace,
+                bgra_to_xrgb_strided, bgra_to_xrgb_inplace_strided;
+            bgra_to_xbgr, bgra_to_xbgr_inplace, Bgra => Xbgr, [3, 0, 1, 2], 3
+                => try_bgra_to_xbgr, try_bgra_to_xbgr_inplace,
+                bgra_to_xbgr_strided, bgra_to_xbgr_inplace_strided;
+            bgra_to_rgb0, bgra_to_rgb0_inplace, Bgra => Rgb0, [2, 1, 0, 3], 3
+                => try_bgra_to_rgb0, try_bgra_to_rgb0_inplace,
+                bgra_to_rgb0_strided, bgra_to_rgb0_inplace_strided;
+            bgra_to_bgr0, bgra_to_bgr0_inplace, Bgra => Bgr0, [0, 1, 2, 3], 3
+                => try_bgra_to_bgr0, try_bgra_to_bgr0_inplace,
+                bgra_to_bgr0_strided, bgra_to_bgr0_inplace_strided;
+            argb_to_rgba, argb_to_rgba_inplace, Argb => Rgba, [1, 2, 3, 0]
+                => try_argb_to_rgba, try_argb_to_rgba_inplace,
+                argb_to_rgba_strided, argb_to_rgba_inplace_strided;
+            argb_to_bgra, argb_to_bgra_inplace, Argb => Bgra, [3, 2, 1, 0]
+                => try_argb_to_bgra, try_argb_to_bgra_inplace,
+                argb_to_bgra_strided, argb_to_bgra_inplace_strided;
+            argb_to_abgr, argb_to_abgr_inplace, Argb => Abgr, [0, 3, 2, 1]
+                => try_argb_to_abgr, try_argb_to_abgr_inplace,
+                argb_to_abgr_strided, argb_to_abgr_inplace_strided;
+            argb_to_rgbx, argb_to_rgbx_inplace, Argb => Rgbx, [1, 2, 3, 0], 0
+                => try_argb_to_rgbx, try_argb_to_rgbx_inplace,
+                argb_to_rgbx_strided, argb_to_rgbx_inplace_strided;
+            argb_to_bgrx, argb_to_bgrx_inplace, Argb => Bgrx, [3, 2, 1, 0], 0
+                => try_argb_to_bgrx, try_argb_to_bgrx_inplace,
+                argb_to_bgrx_strided, argb_to_bgrx_inplace_strided;
+            argb_to_xrgb, argb_to_xrgb_inplace, Argb => Xrgb, [0, 1, 2, 3], 0
+                => try_argb_to_xrgb, try_argb_to_xrgb_inplace,
+                argb_to_xrgb_strided, argb_to_xrgb_inplace_strided;
+            argb_to_xbgr, argb_to_xbgr_inplace, Argb => Xbgr, [0, 3, 2, 1], 0
+                => try_argb_to_xbgr, try_argb_to_xbgr_inplace,
+                argb_to_xbgr_strided, argb_to_xbgr_inplace_strided;
+            argb_to_rgb0, argb_to_rgb0_inplace, Argb => Rgb0, [1, 2, 3, 0], 0
+                => try_argb_to_rgb0, try_argb_to_rgb0_inplace,
+                argb_to_rgb0_strided, argb_to_rgb0_inplace_strided;
+            argb_to_bgr0, argb_to_bgr0_inplace, Argb => Bgr0, [3, 2, 1, 0], 0
+                => try_argb_to_bgr0, try_argb_to_bgr0_inplace,
+                argb_to_bgr0_strided, argb_to_bgr0_inplace_strided;
+            abgr_to_rgba, abgr_to_rgba_inplace, Abgr => Rgba, [3, 2, 1, 0]
+                => try_abgr_to_rgba, try_abgr_to_rgba_inplace,
+                abgr_to_rgba_strided, abgr_to_rgba_inplace_strided;
+            abgr_to_bgra, abgr_to_bgra_inplace, Abgr => Bgra, [1, 2, 3, 0]
+                => try_abgr_to_bgra, try_abgr_to_bgra_inplace,
+                abgr_to_bgra_strided, abgr_to_bgra_inplace_strided;
+            abgr_to_argb, abgr_to_argb_inplace, Abgr => Argb, [0, 3, 2, 1]
+                => try_abgr_to_argb, try_abgr_to_argb_inplace,
+                abgr_to_argb_strided, abgr_to_argb_inplace_strided;
+            abgr_to_rgbx, abgr_to_rgbx_inplace, Abgr => Rgbx, [3, 2, 1, 0], 0
+                => try_abgr_to_rgbx, try_abgr_to_rgbx_inplace,
+                abgr_to_rgbx_strided, abgr_to_rgbx_inplace_strided;
+            abgr_to_bgrx, abgr_to_bgrx_inplace, Abgr => Bgrx, [1, 2, 3, 0], 0
+                => try_abgr_to_bgrx, try_abgr_to_bgrx_inplace,
+                abgr_to_bgrx_strided, abgr_to_bgrx_inplace_strided;
+            abgr_to_xrgb, abgr_to_xrgb_inplace, Abgr => Xrgb, [0, 3, 2, 1], 0
+                => try_abgr_to_xrgb, try_abgr_to_xrgb_inplace,
+                abgr_to_xrgb_strided, abgr_to_xrgb_inplace_strided;
+            abgr_to_xbgr, abgr_to_xbgr_inplace, Abgr => Xbgr, [0, 1, 2, 3], 0
+                => try_abgr_to_xbgr, try_abgr_to_xbgr_inplace,
+                abgr_to_xbgr_strided, abgr_to_xbgr_inplace_strided;
+            abgr_to_rgb0, abgr_to_rgb0_inplace, Abgr => Rgb0, [3, 2, 1, 0], 0
+                => try_abgr_to_rgb0, try_abgr_to_rgb0_inplace,
+                abgr_to_rgb0_strided, abgr_to_rgb0_inplace_strided;
+            abgr_to_bgr0, abgr_to_bgr0_inplace, Abgr => Bgr0, [1, 2, 3, 0], 0
+                => try_abgr_to_bgr0, try_abgr_to_bgr0_inplace,
+                abgr_to_bgr0_strided, abgr_to_bgr0_inplace_strided;
+            rgbx_to_rgba, rgbx_to_rgba_inplace, Rgbx => Rgba, [0, 1, 2, 3], 3
+                => try_rgbx_to_rgba, try_rgbx_to_rgba_inplace,
+                rgbx_to_rgba_strided, rgbx_to_rgba_inplace_strided;
+            rgbx_to_bgra, rgbx_to_bgra_inplace, Rgbx => Bgra, [2, 1, 0, 3], 3
+                => try_rgbx_to_bgra, try_rgbx_to_bgra_inplace,
+                rgbx_to_bgra_strided, rgbx_to_bgra_inplace_strided;
+            rgbx_to_argb, rgbx_to_argb_inplace, Rgbx => Argb, [3, 0, 1, 2], 3
+                => try_rgbx_to_argb, try_rgbx_to_argb_inplace,
+                rgbx_to_argb_strided, rgbx_to_argb_inplace_strided;
+            rgbx_to_abgr, rgbx_to_abgr_inplace, Rgbx => Abgr, [3, 2, 1, 0], 3
+                => try_rgbx_to_abgr, try_rgbx_to_abgr_inplace,
+                rgbx_to_abgr_strided, rgbx_to_abgr_inplace_strided;
+            rgbx_to_bgrx, rgbx_to_bgrx_inplace, Rgbx => Bgrx, [2, 1, 0, 3], 3
+                => try_rgbx_to_bgrx, try_rgbx_to_bgrx_inplace,
+                rgbx_to_bgrx_strided, rgbx_to_bgrx_inplace_strided;
+            rgbx_to_xrgb, rgbx_to_xrgb_inplace, Rgbx => Xrgb, [3, 0, 1, 2], 3
+                => try_rgbx_to_xrgb, try_rgbx_to_xrgb_inplace,
+                rgbx_to_xrgb_strided, rgbx_to_xrgb_inplace_strided;
+            rgbx_to_xbgr, rgbx_to_xbgr_inplace, Rgbx => Xbgr, [3, 2, 1, 0], 3
+                => try_rgbx_to_xbgr, try_rgbx_to_xbgr_inplace,
+                rgbx_to_xbgr_strided, rgbx_to_xbgr_inplace_strided;
+            rgbx_to_rgb0, rgbx_to_rgb0_inplace, Rgbx => Rgb0, [0, 1, 2, 3], 3
+                => try_rgbx_to_rgb0, try_rgbx_to_rgb0_inplace,
+                rgbx_to_rgb0_strided, rgbx_to_rgb0_inplace_strided;
+            rgbx_to_bgr0, rgbx_to_bgr0_inplace, Rgbx => Bgr0, [2, 1, 0, 3], 3
+                => try_rgbx_to_bgr0, try_rgbx_to_bgr0_inplace,
+                rgbx_to_bgr0_strided, rgbx_to_bgr0_inplace_strided;
+            bgrx_to_rgba, bgrx_to_rgba_inplace, Bgrx => Rgba, [2, 1, 0, 3], 3
+                => try_bgrx_to_rgba, try_bgrx_to_rgba_inplace,
+                bgrx_to_rgba_strided, bgrx_to_rgba_inplace_strided;
+            bgrx_to_bgra, bgrx_to_bgra_inplace, Bgrx => Bgra, [0, 1, 2, 3], 3
+                => try_bgrx_to_bgra, try_bgrx_to_bgra_inplace,
+                bgrx_to_bgra_strided, bgrx_to_bgra_inplace_strided;
+            bgrx_to_argb, bgrx_to_argb_inplace, Bgrx => Argb, [3, 2, 1, 0], 3
+                => try_bgrx_to_argb, try_bgrx_to_argb_inplace,
+                bgrx_to_argb_strided, bgrx_to_argb_inplace_strided;
+            bgrx_to_abgr, bgrx_to_abgr_inplace, Bgrx => Abgr, [3, 0, 1, 2], 3
+                => try_bgrx_to_abgr, try_bgrx_to_abgr_inplace,
+                bgrx_to_abgr_strided, bgrx_to_abgr_inplace_strided;
+            bgrx_to_rgbx, bgrx_to_rgbx_inplace, Bgrx => Rgbx, [2, 1, 0, 3], 3
+                => try_bgrx_to_rgbx, try_bgrx_to_rgbx_inplace,
+                bgrx_to_rgbx_strided, bgrx_to_rgbx_inplace_strided;
+            bgrx_to_xrgb, bgrx_to_xrgb_inplace, Bgrx => Xrgb, [3, 2, 1, 0], 3
+                => try_bgrx_to_xrgb, try_bgrx_to_xrgb_inplace,
+                bgrx_to_xrgb_strided, bgrx_to_xrgb_inplace_strided;
+            bgrx_to_xbgr, bgrx_to_xbgr_inplace, Bgrx => Xbgr, [3, 0, 1, 2], 3
+                => try_bgrx_to_xbgr, try_bgrx_to_xbgr_inplace,
+                bgrx_to_xbgr_strided, bgrx_to_xbgr_inplace_strided;
+            bgrx_to_rgb0, bgrx_to_rgb0_inplace, Bgrx => Rgb0, [2, 1, 0, 3], 3
+                => try_bgrx_to_rgb0, try_bgrx_to_rgb0_inplace,
+                bgrx_to_rgb0_strided, bgrx_to_rgb0_inplace_strided;
+            bgrx_to_bgr0, bgrx_to_bgr0_inplace, Bgrx => Bgr0, [0, 1, 2, 3], 3
+                => try_bgrx_to_bgr0, try_bgrx_to_bgr0_inplace,
+                bgrx_to_bgr0_strided, bgrx_to_bgr0_inplace_strided;
+            xrgb_to_rgba, xrgb_to_rgba_inplace, Xrgb => Rgba, [1, 2, 3, 0], 0
+                => try_xrgb_to_rgba, try_xrgb_to_rgba_inplace,
+                xrgb_to_rgba_strided, xrgb_to_rgba_inplace_strided;
+            xrgb_to_bgra, xrgb_to_bgra_inplace, Xrgb => Bgra, [3, 2, 1, 0], 0
+                => try_xrgb_to_bgra, try_xrgb_to_bgra_inplace,
+                xrgb_to_bgra_strided, xrgb_to_bgra_inplace_strided;
+            xrgb_to_argb, xrgb_to_argb_inplace, Xrgb => Argb, [0, 1, 2, 3], 0
+                => try_xrgb_to_argb, try_xrgb_to_argb_inplace,
+                xrgb_to_argb_strided, xrgb_to_argb_inplace_strided;
+            xrgb_to_abgr, xrgb_to_abgr_inplace, Xrgb => Abgr, [0, 3, 2, 1], 0
+                => try_xrgb_to_abgr, try_xrgb_to_abgr_inplace,
+                xrgb_to_abgr_strided, xrgb_to_abgr_inplace_strided;
+            xrgb_to_rgbx, xrgb_to_rgbx_inplace, Xrgb => Rgbx, [1, 2, 3, 0], 0
+                => try_xrgb_to_rgbx, try_xrgb_to_rgbx_inplace,
+                xrgb_to_rgbx_strided, xrgb_to_rgbx_inplace_strided;
+            xrgb_to_bgrx, xrgb_to_bgrx_inplace, Xrgb => Bgrx, [3, 2, 1, 0], 0
+                => try_xrgb_to_bgrx, try_xrgb_to_bgrx_inplace,
+                xrgb_to_bgrx_strided, xrgb_to_bgrx_inplace_strided;
+            xrgb_to_xbgr, xrgb_to_xbgr_inplace, Xrgb => Xbgr, [0, 3, 2, 1], 0
+                => try_xrgb_to_xbgr, try_xrgb_to_xbgr_inplace,
+                xrgb_to_xbgr_strided, xrgb_to_xbgr_inplace_strided;
+            xrgb_to_rgb0, xrgb_to_rgb0_inplace, Xrgb => Rgb0, [1, 2, 3, 0], 0
+                => try_xrgb_to_rgb0, try_xrgb_to_rgb0_inplace,
+                xrgb_to_rgb0_strided, xrgb_to_rgb0_inplace_strided;
+            xrgb_to_bgr0, xrgb_to_bgr0_inplace, Xrgb => Bgr0, [3, 2, 1, 0], 0
+                => try_xrgb_to_bgr0, try_xrgb_to_bgr0_inplace,
+                xrgb_to_bgr0_strided, xrgb_to_bgr0_inplace_strided;
+            xbgr_to_rgba, xbgr_to_rgba_inplace, Xbgr => Rgba, [3, 2, 1, 0], 0
+                => try_xbgr_to_rgba, try_xbgr_to_rgba_inplace,
+                xbgr_to_rgba_strided, xbgr_to_rgba_inplace_strided;
+            xbgr_to_bgra, xbgr_to_bgra_inplace, Xbgr => Bgra, [1, 2, 3, 0], 0
+                => try_xbgr_to_bgra, try_xbgr_to_bgra_inplace,
+                xbgr_to_bgra_strided, xbgr_to_bgra_inplace_strided;
+            xbgr_to_argb, xbgr_to_argb_inplace, Xbgr => Argb, [0, 3, 2, 1], 0
+                => try_xbgr_to_argb, try_xbgr_to_argb_inplace,
+                xbgr_to_argb_strided, xbgr_to_argb_inplace_strided;
+            xbgr_to_abgr, xbgr_to_abgr_inplace, Xbgr => Abgr, [0, 1, 2, 3], 0
+                => try_xbgr_to_abgr, try_xbgr_to_abgr_inplace,
+                xbgr_to_abgr_strided, xbgr_to_abgr_inplace_strided;
+            xbgr_to_rgbx, xbgr_to_rgbx_inplace, Xbgr => Rgbx, [3, 2, 1, 0], 0
+                => try_xbgr_to_rgbx, try_xbgr_to_rgbx_inplace,
+                xbgr_to_rgbx_strided, xbgr_to_rgbx_inplace_strided;
+            xbgr_to_bgrx, xbgr_to_bgrx_inplace, Xbgr => Bgrx, [1, 2, 3, 0], 0
+                => try_xbgr_to_bgrx, try_xbgr_to_bgrx_inplace,
+                xbgr_to_bgrx_strided, xbgr_to_bgrx_inplace_strided;
+            xbgr_to_xrgb, xbgr_to_xrgb_inplace, Xbgr => Xrgb, [0, 3, 2, 1], 0
+                => try_xbgr_to_xrgb, try_xbgr_to_xrgb_inplace,
+                xbgr_to_xrgb_strided, xbgr_to_xrgb_inplace_strided;
+            xbgr_to_rgb0, xbgr_to_rgb0_inplace, Xbgr => Rgb0, [3, 2, 1, 0], 0
+                => try_xbgr_to_rgb0, try_xbgr_to_rgb0_inplace,
+                xbgr_to_rgb0_strided, xbgr_to_rgb0_inplace_strided;
+            xbgr_to_bgr0, xbgr_to_bgr0_inplace, Xbgr => Bgr0, [1, 2, 3, 0], 0
+                => try_xbgr_to_bgr0, try_xbgr_to_bgr0_inplace,
+                xbgr_to_bgr0_strided, xbgr_to_bgr0_inplace_strided;
+            rgb0_to_rgba, rgb0_to_rgba_inplace, Rgb0 => Rgba, [0, 1, 2, 3], 3
+                => try_rgb0_to_rgba, try_rgb0_to_rgba_inplace,
+                rgb0_to_rgba_strided, rgb0_to_rgba_inplace_strided;
+            rgb0_to_bgra, rgb0_to_bgra_inplace, Rgb0 => Bgra, [2, 1, 0, 3], 3
+                => try_rgb0_to_bgra, try_rgb0_to_bgra_inplace,
+                rgb0_to_bgra_strided, rgb0_to_bgra_inplace_strided;
+            rgb0_to_argb, rgb0_to_argb_inplace, Rgb0 => Argb, [3, 0, 1, 2], 3
+                => try_rgb0_to_argb, try_rgb0_to_argb_inplace,
+                rgb0_to_argb_strided, rgb0_to_argb_inplace_strided;
+            rgb0_to_abgr, rgb0_to_abgr_inplace, Rgb0 => Abgr, [3, 2, 1, 0], 3
+                => try_rgb0_to_abgr, try_rgb0_to_abgr_inplace,
+                rgb0_to_abgr_strided, rgb0_to_abgr_inplace_strided;
+            rgb0_to_xrgb, rgb0_to_xrgb_inplace, Rgb0 => Xrgb, [3, 0, 1, 2], 3
+                => try_rgb0_to_xrgb, try_rgb0_to_xrgb_inplace,
+                rgb0_to_xrgb_strided, rgb0_to_xrgb_inplace_strided;
+            rgb0_to_xbgr, rgb0_to_xbgr_inplace, Rgb0 => Xbgr, [3, 2, 1, 0], 3
+                => try_rgb0_to_xbgr, try_rgb0_to_xbgr_inplace,
+                rgb0_to_xbgr_strided, rgb0_to_xbgr_inplace_strided;
+            rgb0_to_bgr0, rgb0_to_bgr0_inplace, Rgb0 => Bgr0, [2, 1, 0, 3], 3
+                => try_rgb0_to_bgr0, try_rgb0_to_bgr0_inplace,
+                rgb0_to_bgr0_strided, rgb0_to_bgr0_inplace_strided;
+            bgr0_to_rgba, bgr0_to_rgba_inplace, Bgr0 => Rgba, [2, 1, 0, 3], 3
+                => try_bgr0_to_rgba, try_bgr0_to_rgba_inplace,
+                bgr0_to_rgba_strided, bgr0_to_rgba_inplace_strided;
+            bgr0_to_bgra, bgr0_to_bgra_inplace, Bgr0 => Bgra, [0, 1, 2, 3], 3
+                => try_bgr0_to_bgra, try_bgr0_to_bgra_inplace,
+                bgr0_to_bgra_strided, bgr0_to_bgra_inplace_strided;
+            bgr0_to_argb, bgr0_to_argb_inplace, Bgr0 => Argb, [3, 2, 1, 0], 3
+                => try_bgr0_to_argb, try_bgr0_to_argb_inplace,
+                bgr0_to_argb_strided, bgr0_to_argb_inplace_strided;
+            bgr0_to_abgr, bgr0_to_abgr_inplace, Bgr0 => Abgr, [3, 0, 1, 2], 3
+                => try_bgr0_to_abgr, try_bgr0_to_abgr_inplace,
+                bgr0_to_abgr_strided, bgr0_to_abgr_inplace_strided;
+            bgr0_to_xrgb, bgr0_to_xrgb_inplace, Bgr0 => Xrgb, [3, 2, 1, 0], 3
+                => try_bgr0_to_xrgb, try_bgr0_to_xrgb_inplace,
+                bgr0_to_xrgb_strided, bgr0_to_xrgb_inplace_strided;
+            bgr0_to_xbgr, bgr0_to_xbgr_inplace, Bgr0 => Xbgr, [3, 0, 1, 2], 3
+                => try_bgr0_to_xbgr, try_bgr0_to_xbgr_inplace,
+                bgr0_to_xbgr_strided, bgr0_to_xbgr_inplace_strided;
+            bgr0_to_rgb0, bgr0_to_rgb0_inplace, Bgr0 => Rgb0, [2, 1, 0, 3], 3
+                => try_bgr0_to_rgb0, try_bgr0_to_rgb0_inplace,
+                bgr0_to_rgb0_strided, bgr0_to_rgb0_inplace_strided;
+        }
+    };
+}
+
+pub(crate) use for_each_conversion;
+
+/// Invokes `$m` with the conversions that exist for every channel type wider than 8 bits,
+/// optionally prefixed by the tokens in braces. Entries look like the ones of
+/// [`for_each_conversion`], the filled lane is set to the opaque value of the channel type.
+#[rustfmt::skip]
+macro_rules! for_each_wide_conversion {
+    ($m:ident $({ $($prefix:tt)* })?) => {
+        $m! {
+            $($($prefix)*)?
+            rgba_to_bgra, rgba_to_bgra_inplace, Rgba => Bgra, [2, 1, 0, 3];
+            bgra_to_rgba, bgra_to_rgba_inplace, Bgra => Rgba, [2, 1, 0, 3];
+            rgba_to_argb, rgba_to_argb_inplace, Rgba => Argb, [3, 0, 1, 2];
+            argb_to_rgba, argb_to_rgba_inplace, Argb => Rgba, [1, 2, 3, 0];
+            rgb0_to_rgbx, rgb0_to_rgbx_inplace, Rgb0 => Rgbx, [0, 1, 2, 3], 3;
+            bgr0_to_bgrx, bgr0_to_bgrx_inplace, Bgr0 => Bgrx, [0, 1, 2, 3], 3;
+            rgb0_to_bgrx, rgb0_to_bgrx_inplace, Rgb0 => Bgrx, [2, 1, 0, 3], 3;
+            bgr0_to_rgbx, bgr0_to_rgbx_inplace, Bgr0 => Rgbx, [2, 1, 0, 3], 3;
+        }
+    };
+}
+
+pub(crate) use for_each_wide_conversion;
+
+/// Implements the public functions of [`for_each_wide_conversion`] on top of the `$module`
+/// module of the backend. With `swap` the functions take the byte order of the source, with
+/// `keep` the source is in native endian.
+macro_rules! impl_wide_wrappers {
+    (
+        $module:ident, $elem:ty, $swap:ident;
+        $($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*
+    ) => {
+        $(
+            crate::common::impl_wide_wrappers!(@fn $swap, $module, $elem, $name, $inplace, $src, $dst);
+        )*
+    };
+    (@fn swap, $module:ident, $elem:ty, $name:ident, $inplace:ident, $src:ident, $dst:ident) => {
+        #[doc = concat!(
+            "Convert [`PixelFormat::", stringify!($src), "`](crate::PixelFormat::", stringify!($src),
+            ") data in `byte_order` to native endian [`PixelFormat::", stringify!($dst),
+            "`](crate::PixelFormat::", stringify!($dst), ") data while overwriting the old data in `src`."
+        )]
+        ///
+        /// Panics if `src.len` is not multiple of a 4.
+        #[inline]
+        pub fn $inplace(src: &mut [$elem], byte_order: crate::ByteOrder) {
+            #[cfg(feature = "nightly")]
+            crate::simd::$module::$inplace(src, byte_order);
+            #[cfg(not(feature = "nightly"))]
+            crate::sisd::$module::$inplace(src, byte_order);
+        }
+
+        #[doc = concat!(
+            "Convert [`PixelFormat::", stringify!($src), "`](crate::PixelFormat::", stringify!($src),
+            ") data in `byte_order` to native endian [`PixelFormat::", stringify!($dst),
+            "`](crate::PixelFormat::", stringify!($dst), ") data and store the result to `dst`."
+        )]
+        ///
+        /// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+        #[inline]
+        pub fn $name(src: &[$elem], dst: &mut [$elem], byte_order: crate::ByteOrder) {
+            #[cfg(feature = "nightly")]
+            crate::simd::$module::$name(src, dst, byte_order);
+            #[cfg(not(feature = "nightly"))]
+            crate::sisd::$module::$name(src, dst, byte_order);
+        }
+    };
+    (@fn keep, $module:ident, $elem:ty, $name:ident, $inplace:ident, $src:ident, $dst:ident) => {
+        #[doc = concat!(
+            "Convert [`PixelFormat::", stringify!($src), "`](crate::PixelFormat::", stringify!($src),
+            ") data to [`PixelFormat::", stringify!($dst), "`](crate::PixelFormat::", stringify!($dst),
+            ") while overwriting the old data in `src`."
+        )]
+        ///
+        /// Panics if `src.len` is not multiple of a 4.
+        #[inline]
+        pub fn $inplace(src: &mut [$elem]) {
+            #[cfg(feature = "nightly")]
+            crate::simd::$module::$inplace(src);
+            #[cfg(not(feature = "nightly"))]
+            crate::sisd::$module::$inplace(src);
+        }
+
+        #[doc = concat!(
+            "Convert [`PixelFormat::", stringify!($src), "`](crate::PixelFormat::", stringify!($src),
+            ") data to [`PixelFormat::", stringify!($dst), "`](crate::PixelFormat::", stringify!($dst),
+            ") and store the result to `dst`."
+        )]
+        ///
+        /// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+        #[inline]
+        pub fn $name(src: &[$elem], dst: &mut [$elem]) {
+            #[cfg(feature = "nightly")]
+            crate::simd::$module::$name(src, dst);
+            #[cfg(not(feature = "nightly"))]
+            crate::sisd::$module::$name(src, dst);
+        }
+    };
+}
+
+pub(crate) use impl_wide_wrappers;
+
+/// Invokes `$m` with every expansion from 3 byte RGB or BGR pixels to a 4 channel format. Every
+/// entry is `name, Src => Dst, [order], alpha;` where output channel `i` is taken from input
+/// channel `order[i]` and channel 3 is the fill value. Entries marked `alpha` take the fill
+/// value as an argument, the others fill with 255.
+#[rustfmt::skip]
+macro_rules! for_each_expansion {
+    ($m:ident) => {
+        $m! {
+            rgb24_to_rgba, Rgb24 => Rgba, [0, 1, 2, 3], alpha;
+            rgb24_to_bgra, Rgb24 => Bgra, [2, 1, 0, 3], alpha;
+            rgb24_to_argb, Rgb24 => Argb, [3, 0, 1, 2], alpha;
+            rgb24_to_abgr, Rgb24 => Abgr, [3, 2, 1, 0], alpha;
+            rgb24_to_rgbx, Rgb24 => Rgbx, [0, 1, 2, 3];
+            rgb24_to_bgrx, Rgb24 => Bgrx, [2, 1, 0, 3];
+            rgb24_to_xrgb, Rgb24 => Xrgb, [3, 0, 1, 2];
+            rgb24_to_xbgr, Rgb24 => Xbgr, [3, 2, 1, 0];
+            bgr24_to_rgba, Bgr24 => Rgba, [2, 1, 0, 3], alpha;
+            bgr24_to_bgra, Bgr24 => Bgra, [0, 1, 2, 3], alpha;
+            bgr24_to_argb, Bgr24 => Argb, [3, 2, 1, 0], alpha;
+            bgr24_to_abgr, Bgr24 => Abgr, [3, 0, 1, 2], alpha;
+            bgr24_to_rgbx, Bgr24 => Rgbx, [2, 1, 0, 3];
+            bgr24_to_bgrx, Bgr24 => Bgrx, [0, 1, 2, 3];
+            bgr24_to_xrgb, Bgr24 => Xrgb, [3, 2, 1, 0];
+            bgr24_to_xbgr, Bgr24 => Xbgr, [3, 0, 1, 2];
+        }
+    };
+}
+
+pub(crate) use for_each_expansion;
+
+/// Invokes `$m` with every packing from a 4 channel format to 3 byte RGB or BGR pixels. Every
+/// entry is `name, Src => Dst, [order];` where output channel `i` is taken from input channel
+/// `order[i]`.
+#[rustfmt::skip]
+macro_rules! for_each_packing {
+    ($m:ident) => {
+        $m! {
+            rgba_to_rgb24, Rgba => Rgb24, [0, 1, 2];
+            rgba_to_bgr24, Rgba => Bgr24, [2, 1, 0];
+            bgra_to_rgb24, Bgra => Rgb24, [2, 1, 0];
+            bgra_to_bgr24, Bgra => Bgr24, [0, 1, 2];
+            argb_to_rgb24, Argb => Rgb24, [1, 2, 3];
+            argb_to_bgr24, Argb => Bgr24, [3, 2, 1];
+            abgr_to_rgb24, Abgr => Rgb24, [3, 2, 1];
+            abgr_to_bgr24, Abgr => Bgr24, [1, 2, 3];
+            rgbx_to_rgb24, Rgbx => Rgb24, [0, 1, 2];
+            rgbx_to_bgr24, Rgbx => Bgr24, [2, 1, 0];
+            bgrx_to_rgb24, Bgrx => Rgb24, [2, 1, 0];
+            bgrx_to_bgr24, Bgrx => Bgr24, [0, 1, 2];
+            xrgb_to_rgb24, Xrgb => Rgb24, [1, 2, 3];
+            xrgb_to_bgr24, Xrgb => Bgr24, [3, 2, 1];
+            xbgr_to_rgb24, Xbgr => Rgb24, [3, 2, 1];
+            xbgr_to_bgr24, Xbgr => Bgr24, [1, 2, 3];
+            rgb0_to_rgb24, Rgb0 => Rgb24, [0, 1, 2];
+            rgb0_to_bgr24, Rgb0 => Bgr24, [2, 1, 0];
+            bgr0_to_rgb24, Bgr0 => Rgb24, [2, 1, 0];
+            bgr0_to_bgr24, Bgr0 => Bgr24, [0, 1, 2];
+        }
+    };
+}
+
+pub(crate) use for_each_packing;
+
+/// Invokes `$m` with every unpacking of 16 bit 565 pixels to 4 channels. Every entry is
+/// `name, Src => Dst, [order];` where output channel `i` is taken from field `order[i]` of the
+/// pixel, counted from the most significant bits, and field 3 is an opaque alpha.
+#[rustfmt::skip]
+macro_rules! for_each_565_unpacking {
+    ($m:ident) => {
+        $m! {
+            rgb565_to_rgba, Rgb565 => Rgba, [0, 1, 2, 3];
+            rgb565_to_bgra, Rgb565 => Bgra, [2, 1, 0, 3];
+            bgr565_to_rgba, Bgr565 => Rgba, [2, 1, 0, 3];
+            bgr565_to_bgra, Bgr565 => Bgra, [0, 1, 2, 3];
+        }
+    };
+}
+
+pub(crate) use for_each_565_unpacking;
+
+/// Invokes `$m` with every packing of 4 channels to 16 bit 565 pixels. Every entry is
+/// `name, Src => Dst, [order];` where field `i` of the pixel, counted from the most significant
+/// bits, is taken from input channel `order[i]`.
+#[rustfmt::skip]
+macro_rules! for_each_565_packing {
+    ($m:ident) => {
+        $m! {
+            rgba_to_rgb565, Rgba => Rgb565, [0, 1, 2];
+            bgra_to_rgb565, Bgra => Rgb565, [2, 1, 0];
+            rgba_to_bgr565, Rgba => Bgr565, [2, 1, 0];
+            bgra_to_bgr565, Bgra => Bgr565, [0, 1, 2];
+        }
+    };
+}
+
+pub(crate) use for_each_565_packing;
+
+/// Tests every function of [`for_each_conversion`] that is in scope together with the
+/// conversions that are written out by hand, and checks that they cover every pair of formats.
+#[cfg(test)]
+macro_rules! check_conversions {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*) => {
+        check_conversions!(@check
+            $($name, $inplace, $src => $dst;)*
+            rgba_to_bgra, rgba_to_bgra_inplace, Rgba => Bgra;
+            bgra_to_rgba, bgra_to_rgba_inplace, Bgra => Rgba;
+            rgb0_to_rgbx, rgb0_to_rgbx_inplace, Rgb0 => Rgbx;
+            bgr0_to_bgrx, bgr0_to_bgrx_inplace, Bgr0 => Bgrx;
+            rgb0_to_bgrx, rgb0_to_bgrx_inplace, Rgb0 => Bgrx;
+            bgr0_to_rgbx, bgr0_to_rgbx_inplace, Bgr0 => Rgbx;
+        );
+    };
+    (@check $($name:ident, $inplace:ident, $src:ident => $dst:ident;)*) => {
+        $(
+            let (src_fmt, dst_fmt) = (crate::PixelFormat::$src, crate::PixelFormat::$dst);
+            let src = crate::common::test_pixel(src_fmt).repeat(37);
+
+            let mut dst = vec![0; src.len()];
+            $name(&src, &mut dst);
+            crate::common::check_conversion(src_fmt, dst_fmt, &dst);
+
+            let mut inplace = src.clone();
+            $inplace(&mut inplace);
+            crate::common::check_conversion(src_fmt, dst_fmt, &inplace);
+        )*
+
+        crate::common::check_matrix(&[$((crate::PixelFormat::$src, crate::PixelFormat::$dst)),*]);
+    };
+}
+
+/// Checks that `pairs` holds every pair of different formats exactly once.
+#[cfg(test)]
+pub(crate) fn check_matrix(pairs: &[(crate::PixelFormat, crate::PixelFormat)]) {
+    let formats = crate::PixelFormat::ALL;
+    for src in formats {
+        for dst in formats.into_iter().filter(|&dst| dst != src) {
+            let count = pairs.iter().filter(|&&pair| pair == (src, dst)).count();
+            assert_eq!(count, 1, "{src:?} -> {dst:?}");
+        }
+    }
+    assert_eq!(pairs.len(), formats.len() * (formats.len() - 1));
+}
+
+#[cfg(test)]
+pub(crate) use check_conversions;
+
+/// Tests every function of [`for_each_expansion`] that is in scope. The alpha value is 4 so
+/// [`check_conversion`] can treat the input as RGBA or BGRA.
+#[cfg(test)]
+macro_rules! check_expansions {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $alpha:ident)?;)*) => {
+        $(
+            let rgb = stringify!($src) == "Rgb24";
+            let has_alpha = stringify!($($alpha)?) == "alpha";
+            let src_fmt = match (rgb, has_alpha) {
+                (true, true) => crate::PixelFormat::Rgba,
+                (true, false) => crate::PixelFormat::Rgb0,
+                (false, true) => crate::PixelFormat::Bgra,
+                (false, false) => crate::PixelFormat::Bgr0,
+            };
+            let src = crate::common::test_pixel(src_fmt)[..3].repeat(37);
+
+            let mut dst = vec![0; 37 * 4];
+            crate::common::check_expansions!(@call $name, &src, &mut dst $(, $alpha)?);
+            crate::common::check_conversion(src_fmt, crate::PixelFormat::$dst, &dst);
+        )*
+    };
+    (@call $name:ident, $src:expr, $dst:expr, alpha) => {
+        $name($src, $dst, 4)
+    };
+    (@call $name:ident, $src:expr, $dst:expr) => {
+        $name($src, $dst)
+    };
+}
+
+#[cfg(test)]
+pub(crate) use check_expansions;
+
+/// Tests every function of [`for_each_packing`] that is in scope.
+#[cfg(test)]
+macro_rules! check_packings {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+        $(
+            let src = crate::common::test_pixel(crate::PixelFormat::$src).repeat(37);
+            let expected: [u8; 3] = match stringify!($dst) {
+                "Rgb24" => [1, 2, 3],
+                _ => [3, 2, 1],
+            };
+
+            let mut dst = vec![0; 37 * 3];
+            $name(&src, &mut dst);
+            assert_eq!(dst, expected.repeat(37), stringify!($name));
+        )*
+    };
+}
+
+#[cfg(test)]
+pub(crate) use check_packings;
+
+/// Tests every function of [`for_each_wide_conversion`] in `$module`, the channels are `$elem`
+/// and the arguments in `$args` are passed to every function.
+#[cfg(test)]
+macro_rules! check_wide_conversions {
+    (
+        $module:ident, $elem:ty, $opaque:expr, $args:tt;
+        $($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*
+    ) => {
+        $(
+            let (src_fmt, dst_fmt) = (crate::PixelFormat::$src, crate::PixelFormat::$dst);
+            let src = crate::common::test_pixel(src_fmt).map(<$elem>::from).repeat(37);
+
+            let mut dst = vec![<$elem>::from(0u8); src.len()];
+            check_wide_conversions!(@call $module::$name, (&src, &mut dst), $args);
+            crate::common::check_wide_conversion(src_fmt, dst_fmt, &dst, $opaque);
+
+            let mut inplace = src.clone();
+            check_wide_conversions!(@call $module::$inplace, (&mut inplace), $args);
+            crate::common::check_wide_conversion(src_fmt, dst_fmt, &inplace, $opaque);
+        )*
+    };
+    (@call $f:path, ($($buf:expr),*), ($($arg:expr),*)) => {
+        $f($($buf,)* $($arg),*)
+    };
+}
+
+#[cfg(test)]
+pub(crate) use check_wide_conversions;
+
+#[cfg(test)]
+pub(crate) fn test_pixel(format: crate::PixelFormat) -> [u8; 4] {
+    use crate::format::Channel;
+    format.channels().map(|channel| match channel {
+        Channel::R => 1,
+        Channel::G => 2,
+        Channel::B => 3,
+        Channel::A => 4,
+        Channel::X | Channel::Zero => 9,
+    })
+}
+
+/// Checks that every pixel of `dst` is [`test_pixel`] in `src_fmt` converted to `dst_fmt`.
+#[cfg(test)]
+pub(crate) fn check_conversion(
+    src_fmt: crate::PixelFormat,
+    dst_fmt: crate::PixelFormat,
+    dst: &[u8],
+) {
+    check_wide_conversion(src_fmt, dst_fmt, dst, 255);
+}
+
+/// [`check_conversion`] for any channel type, `opaque` is the value of filled channels.
+#[cfg(test)]
+pub(crate) fn check_wide_conversion<T>(
+    src_fmt: crate::PixelFormat,
+    dst_fmt: crate::PixelFormat,
+    dst: &[T],
+    opaque: T,
+) where
+    T: Copy + PartialEq + std::fmt::Debug + From<u8>,
+{
+    use crate::format::Channel;
+    for px in dst.chunks_exact(4) {
+        for (value, channel) in px.iter().zip(dst_fmt.channels()) {
+            let expected = match channel {
+                Channel::R => T::from(1),
+                Channel::G => T::from(2),
+                Channel::B => T::from(3),
+                Channel::A if src_fmt.has_alpha() => T::from(4),
+                Channel::A | Channel::X => opaque,
+                Channel::Zero => continue,
+            };
+            assert_eq!(*value, expected, "{src_fmt:?} -> {dst_fmt:?} {px:?}");
+        }
+    }
+}
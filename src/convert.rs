@@ -0,0 +1,183 @@
+use crate::error::{check_len, check_lens};
+use crate::format::Plan;
+use crate::{PixelFormat, SwizzleError};
+
+macro_rules! impl_kernels {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*) => {
+        /// Returns the dedicated kernel for the conversion from `src` to `dst`, if any.
+        fn kernel(src: PixelFormat, dst: PixelFormat) -> Option<fn(&[u8], &mut [u8])> {
+            match (src, dst) {
+                $((PixelFormat::$src, PixelFormat::$dst) => Some(crate::$name),)*
+                _ => None,
+            }
+        }
+
+        /// Returns the dedicated in-place kernel for the conversion from `src` to `dst`, if any.
+        fn kernel_inplace(src: PixelFormat, dst: PixelFormat) -> Option<fn(&mut [u8])> {
+            match (src, dst) {
+                $((PixelFormat::$src, PixelFormat::$dst) => Some(crate::$inplace),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+crate::common::for_each_conversion!(impl_kernels);
+
+/// Convert `src` in the `src_fmt` format to `dst_fmt` and store the result to `dst`.
+///
+/// The conversion is routed to the fastest available kernel for the pair of formats.
+///
+/// ```rust
+/// use image_swizzle::{convert, PixelFormat};
+/// let xrgb = [0, 1, 2, 3];
+/// let mut bgra = [0; 4];
+/// convert(&xrgb, PixelFormat::Xrgb, &mut bgra, PixelFormat::Bgra).unwrap();
+/// assert_eq!(bgra, [3, 2, 1, 255]);
+/// ```
+///
+/// Returns an error if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+pub fn convert(
+    src: &[u8],
+    src_fmt: PixelFormat,
+    dst: &mut [u8],
+    dst_fmt: PixelFormat,
+) -> Result<(), SwizzleError> {
+    check_lens(src.len(), dst.len(), 4)?;
+
+    if let Some(kernel) = kernel(src_fmt, dst_fmt) {
+        kernel(src, dst);
+        return Ok(());
+    }
+
+    match src_fmt.plan_to(dst_fmt) {
+        Plan {
+            order: [0, 1, 2, 3],
+            fill: None,
+        } => dst.copy_from_slice(src),
+        Plan {
+            order: [2, 1, 0, 3],
+            fill: None,
+        } => crate::rgba_to_bgra(src, dst),
+        Plan {
+            order: [0, 1, 2, 3],
+            fill: Some(3),
+        } => crate::rgb0_to_rgbx(src, dst),
+        Plan {
+            order: [2, 1, 0, 3],
+            fill: Some(3),
+        } => crate::rgb0_to_bgrx(src, dst),
+        Plan { order, fill: None } => crate::swizzle4(src, dst, order),
+        Plan {
+            order,
+            fill: Some(lane),
+        } => crate::swizzle4_fill(src, dst, order, order[lane] as usize, 255),
+    }
+
+    Ok(())
+}
+
+/// Convert `src` from the `src_fmt` format to `dst_fmt` while overwriting the old data in `src`.
+///
+/// ```rust
+/// use image_swizzle::{convert_inplace, PixelFormat};
+/// let mut abgr = [255, 3, 2, 1];
+/// convert_inplace(&mut abgr, PixelFormat::Abgr, PixelFormat::Rgba).unwrap();
+/// assert_eq!(abgr, [1, 2, 3, 255]);
+/// ```
+///
+/// Returns an error if `src.len` is not multiple of a 4.
+pub fn convert_inplace(
+    src: &mut [u8],
+    src_fmt: PixelFormat,
+    dst_fmt: PixelFormat,
+) -> Result<(), SwizzleError> {
+    check_len(src.len(), 4)?;
+
+    if let Some(kernel) = kernel_inplace(src_fmt, dst_fmt) {
+        kernel(src);
+        return Ok(());
+    }
+
+    match src_fmt.plan_to(dst_fmt) {
+        Plan {
+            order: [0, 1, 2, 3],
+            fill: None,
+        } => (),
+        Plan {
+            order: [2, 1, 0, 3],
+            fill: None,
+        } => crate::rgba_to_bgra_inplace(src),
+        Plan {
+            order: [1, 2, 3, 0],
+            fill: None,
+        } => crate::argb_to_rgba_inplace(src),
+        Plan {
+            order: [0, 1, 2, 3],
+            fill: Some(3),
+        } => crate::rgb0_to_rgbx_inplace(src),
+        Plan {
+            order: [2, 1, 0, 3],
+            fill: Some(3),
+        } => crate::rgb0_to_bgrx_inplace(src),
+        Plan { order, fill: None } => crate::swizzle4_inplace(src, order),
+        Plan {
+            order,
+            fill: Some(lane),
+        } => crate::swizzle4_fill_inplace(src, order, order[lane] as usize, 255),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{check_conversion, test_pixel};
+
+    #[test]
+    fn test_convert_all_pairs() {
+        for src_fmt in PixelFormat::ALL {
+            for dst_fmt in PixelFormat::ALL {
+                let src = test_pixel(src_fmt).repeat(37);
+                let mut dst = vec![0; src.len()];
+                convert(&src, src_fmt, &mut dst, dst_fmt).unwrap();
+                check_conversion(src_fmt, dst_fmt, &dst);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_inplace_all_pairs() {
+        for src_fmt in PixelFormat::ALL {
+            for dst_fmt in PixelFormat::ALL {
+                let mut src = test_pixel(src_fmt).repeat(37);
+                convert_inplace(&mut src, src_fmt, dst_fmt).unwrap();
+                check_conversion(src_fmt, dst_fmt, &src);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_invalid_lengths() {
+        let mut dst = [0; 4];
+        assert_eq!(
+            convert(&[0; 8], PixelFormat::Rgba, &mut dst, PixelFormat::Bgra),
+            Err(SwizzleError::LengthMismatch { src: 8, dst: 4 })
+        );
+        assert_eq!(
+            convert(&[0; 5], PixelFormat::Rgba, &mut dst, PixelFormat::Bgra),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 5,
+                pixel_size: 4
+            })
+        );
+        assert_eq!(
+            convert_inplace(&mut [0; 7], PixelFormat::Rgba, PixelFormat::Bgra),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 7,
+                pixel_size: 4
+            })
+        );
+    }
+}
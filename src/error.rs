@@ -0,0 +1,126 @@
+use std::fmt;
+
+use crate::{ChannelMasks, Rect};
+
+/// Errors returned by the fallible conversion routines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwizzleError {
+    /// The length of a buffer is not a multiple of the pixel size.
+    LengthNotMultiple { len: usize, pixel_size: usize },
+    /// The destination buffer does not have the same length as the source buffer.
+    LengthMismatch { src: usize, dst: usize },
+    /// A row stride is smaller than the length of a row of pixels.
+    StrideTooSmall { stride: usize, min: usize },
+    /// A buffer is too small to hold the image it is supposed to describe.
+    BufferTooSmall { len: usize, min: usize },
+    /// The source and destination images do not have the same dimensions.
+    DimensionMismatch {
+        src: (usize, usize),
+        dst: (usize, usize),
+    },
+    /// A region does not fit inside the image it was taken from.
+    RegionOutOfBounds {
+        region: Rect,
+        width: usize,
+        height: usize,
+    },
+    /// A channel order contains an index that is out of range.
+    InvalidOrder { order: [u8; 4] },
+    /// A fourcc code does not name a supported pixel format.
+    UnknownFourcc { code: u32 },
+    /// Channel masks that overlap, are not contiguous or do not fit in a pixel.
+    InvalidMasks { masks: ChannelMasks },
+    /// An RFB pixel format uses a colour map instead of true colour.
+    NotTrueColour,
+}
+
+impl fmt::Display for SwizzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SwizzleError::LengthNotMultiple { len, pixel_size } => {
+                write!(f, "buffer length {len} is not a multiple of {pixel_size}")
+            }
+            SwizzleError::LengthMismatch { src, dst } => {
+                write!(f, "source length {src} does not match destination length {dst}")
+            }
+            SwizzleError::StrideTooSmall { stride, min } => {
+                write!(f, "stride {stride} is smaller than the row length {min}")
+            }
+            SwizzleError::BufferTooSmall { len, min } => {
+                write!(f, "buffer length {len} is smaller than the required {min}")
+            }
+            SwizzleError::DimensionMismatch { src, dst } => write!(
+                f,
+                "source dimensions {}x{} do not match destination dimensions {}x{}",
+                src.0, src.1, dst.0, dst.1
+            ),
+            SwizzleError::RegionOutOfBounds {
+                region,
+                width,
+                height,
+            } => write!(
+                f,
+                "region {}x{}+{}+{} is outside of the {width}x{height} image",
+                region.width, region.height, region.x, region.y
+            ),
+            SwizzleError::InvalidOrder { order } => {
+                write!(f, "channel order {order:?} contains an index greater than 3")
+            }
+            SwizzleError::UnknownFourcc { code } => {
+                write!(f, "unknown fourcc code {code:#010x}")
+            }
+            SwizzleError::InvalidMasks { masks } => {
+                write!(f, "unsupported channel masks {masks:?}")
+            }
+            SwizzleError::NotTrueColour => write!(f, "colour map pixel formats are not supported"),
+        }
+    }
+}
+
+impl std::error::Error for SwizzleError {}
+
+pub(crate) fn check_len(len: usize, pixel_size: usize) -> Result<(), SwizzleError> {
+    if !len.is_multiple_of(pixel_size) {
+        return Err(SwizzleError::LengthNotMultiple { len, pixel_size });
+    }
+    Ok(())
+}
+
+pub(crate) fn check_lens(src: usize, dst: usize, pixel_size: usize) -> Result<(), SwizzleError> {
+    check_len(src, pixel_size)?;
+    if src != dst {
+        return Err(SwizzleError::LengthMismatch { src, dst });
+    }
+    Ok(())
+}
+
+pub(crate) fn check_order(order: [u8; 4]) -> Result<(), SwizzleError> {
+    if order.iter().any(|&i| i > 3) {
+        return Err(SwizzleError::InvalidOrder { order });
+    }
+    Ok(())
+}
+
+/// Checks that a buffer of `len` bytes can hold `height` rows of `row_len` bytes that are
+/// `stride` bytes apart.
+pub(crate) fn check_rows(
+    len: usize,
+    stride: usize,
+    row_len: usize,
+    height: usize,
+) -> Result<(), SwizzleError> {
+    if stride < row_len {
+        return Err(SwizzleError::StrideTooSmall {
+            stride,
+            min: row_len,
+        });
+    }
+    if height == 0 {
+        return Ok(());
+    }
+    let min = stride.saturating_mul(height - 1).saturating_add(row_len);
+    if len < min {
+        return Err(SwizzleError::BufferTooSmall { len, min });
+    }
+    Ok(())
+}
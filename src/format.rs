@@ -0,0 +1,238 @@
+/// Memory byte order of a pixel with 4 channels of 8 bits each.
+///
+/// Formats with a padding byte come in two flavours following the naming of the conversion
+/// functions: the padding byte of `*0` formats has unspecified contents, while the padding byte
+/// of `*x` formats is always 255. The padding byte is never read, when it is written it is set
+/// to 255 for `*x` formats and left unspecified for `*0` formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    Rgba,
+    Bgra,
+    Argb,
+    Abgr,
+    Rgb0,
+    Bgr0,
+    Rgbx,
+    Bgrx,
+    Xrgb,
+    Xbgr,
+}
+
+/// Memory byte order of a pixel with 3 channels of 8 bits each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelFormat24 {
+    Rgb24,
+    Bgr24,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Channel {
+    R,
+    G,
+    B,
+    A,
+    /// Padding that is set to 255 when written.
+    X,
+    /// Padding with unspecified contents.
+    Zero,
+}
+
+/// How to get from one [`PixelFormat`] to another: output channel `i` is taken from input
+/// channel `order[i]`, unless it is `fill` which is set to 255.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Plan {
+    pub order: [u8; 4],
+    pub fill: Option<usize>,
+}
+
+impl PixelFormat {
+    /// Every format, in declaration order.
+    pub(crate) const ALL: [PixelFormat; 10] = [
+        PixelFormat::Rgba,
+        PixelFormat::Bgra,
+        PixelFormat::Argb,
+        PixelFormat::Abgr,
+        PixelFormat::Rgb0,
+        PixelFormat::Bgr0,
+        PixelFormat::Rgbx,
+        PixelFormat::Bgrx,
+        PixelFormat::Xrgb,
+        PixelFormat::Xbgr,
+    ];
+
+    pub(crate) const fn channels(self) -> [Channel; 4] {
+        use Channel::*;
+        match self {
+            PixelFormat::Rgba => [R, G, B, A],
+            PixelFormat::Bgra => [B, G, R, A],
+            PixelFormat::Argb => [A, R, G, B],
+            PixelFormat::Abgr => [A, B, G, R],
+            PixelFormat::Rgb0 => [R, G, B, Zero],
+            PixelFormat::Bgr0 => [B, G, R, Zero],
+            PixelFormat::Rgbx => [R, G, B, X],
+            PixelFormat::Bgrx => [B, G, R, X],
+            PixelFormat::Xrgb => [X, R, G, B],
+            PixelFormat::Xbgr => [X, B, G, R],
+        }
+    }
+
+    /// Returns the format with the byte order reversed, i.e. the layout of a big endian packed
+    /// pixel when it is stored as little endian. The padding byte of `*0` formats has no
+    /// defined value so they reverse to the `x*` formats.
+    pub(crate) const fn reversed(self) -> PixelFormat {
+        match self {
+            PixelFormat::Rgba => PixelFormat::Abgr,
+            PixelFormat::Bgra => PixelFormat::Argb,
+            PixelFormat::Argb => PixelFormat::Bgra,
+            PixelFormat::Abgr => PixelFormat::Rgba,
+            PixelFormat::Rgb0 | PixelFormat::Rgbx => PixelFormat::Xbgr,
+            PixelFormat::Bgr0 | PixelFormat::Bgrx => PixelFormat::Xrgb,
+            PixelFormat::Xrgb => PixelFormat::Bgrx,
+            PixelFormat::Xbgr => PixelFormat::Rgbx,
+        }
+    }
+
+    /// Parse an ffmpeg `AVPixelFormat` name as used by `-pix_fmt`, e.g. `"rgb0"` or `"bgra"`.
+    ///
+    /// ffmpeg has no formats with a defined padding value so `"0rgb"` and `"0bgr"` map to
+    /// [`PixelFormat::Xrgb`] and [`PixelFormat::Xbgr`].
+    ///
+    /// ```rust
+    /// use image_swizzle::PixelFormat;
+    /// assert_eq!(PixelFormat::from_ffmpeg_name("bgr0"), Some(PixelFormat::Bgr0));
+    /// assert_eq!(PixelFormat::from_ffmpeg_name("yuv420p"), None);
+    /// ```
+    pub fn from_ffmpeg_name(name: &str) -> Option<PixelFormat> {
+        Some(match name {
+            "rgba" => PixelFormat::Rgba,
+            "bgra" => PixelFormat::Bgra,
+            "argb" => PixelFormat::Argb,
+            "abgr" => PixelFormat::Abgr,
+            "rgb0" => PixelFormat::Rgb0,
+            "bgr0" => PixelFormat::Bgr0,
+            "0rgb" => PixelFormat::Xrgb,
+            "0bgr" => PixelFormat::Xbgr,
+            _ => return None,
+        })
+    }
+
+    /// Returns the name of the ffmpeg `AVPixelFormat` with the same byte order.
+    pub const fn ffmpeg_name(self) -> &'static str {
+        match self {
+            PixelFormat::Rgba => "rgba",
+            PixelFormat::Bgra => "bgra",
+            PixelFormat::Argb => "argb",
+            PixelFormat::Abgr => "abgr",
+            PixelFormat::Rgb0 | PixelFormat::Rgbx => "rgb0",
+            PixelFormat::Bgr0 | PixelFormat::Bgrx => "bgr0",
+            PixelFormat::Xrgb => "0rgb",
+            PixelFormat::Xbgr => "0bgr",
+        }
+    }
+
+    /// Parse a GStreamer `GstVideoFormat` name as used in caps, e.g. `"BGRx"` or `"RGBA"`.
+    ///
+    /// ```rust
+    /// use image_swizzle::PixelFormat;
+    /// assert_eq!(PixelFormat::from_gstreamer_name("xRGB"), Some(PixelFormat::Xrgb));
+    /// assert_eq!(PixelFormat::from_gstreamer_name("NV12"), None);
+    /// ```
+    pub fn from_gstreamer_name(name: &str) -> Option<PixelFormat> {
+        Some(match name {
+            "RGBA" => PixelFormat::Rgba,
+            "BGRA" => PixelFormat::Bgra,
+            "ARGB" => PixelFormat::Argb,
+            "ABGR" => PixelFormat::Abgr,
+            "RGBx" => PixelFormat::Rgbx,
+            "BGRx" => PixelFormat::Bgrx,
+            "xRGB" => PixelFormat::Xrgb,
+            "xBGR" => PixelFormat::Xbgr,
+            _ => return None,
+        })
+    }
+
+    /// Returns the name of the GStreamer `GstVideoFormat` with the same byte order.
+    pub const fn gstreamer_name(self) -> &'static str {
+        match self {
+            PixelFormat::Rgba => "RGBA",
+            PixelFormat::Bgra => "BGRA",
+            PixelFormat::Argb => "ARGB",
+            PixelFormat::Abgr => "ABGR",
+            PixelFormat::Rgb0 | PixelFormat::Rgbx => "RGBx",
+            PixelFormat::Bgr0 | PixelFormat::Bgrx => "BGRx",
+            PixelFormat::Xrgb => "xRGB",
+            PixelFormat::Xbgr => "xBGR",
+        }
+    }
+
+    /// Returns `true` if the format carries an alpha channel.
+    pub fn has_alpha(self) -> bool {
+        self.channels().contains(&Channel::A)
+    }
+
+    pub(crate) fn plan_to(self, dst: PixelFormat) -> Plan {
+        let src = self.channels();
+        let lane = |channel| src.iter().position(|&c| c == channel);
+        // The lane that does not hold a colour channel, i.e. alpha or padding.
+        let extra = lane(Channel::A)
+            .or(lane(Channel::X))
+            .or(lane(Channel::Zero))
+            .unwrap() as u8;
+
+        let mut plan = Plan {
+            order: [0; 4],
+            fill: None,
+        };
+        for (i, channel) in dst.channels().into_iter().enumerate() {
+            plan.order[i] = match channel {
+                Channel::R | Channel::G | Channel::B => lane(channel).unwrap() as u8,
+                Channel::A if self.has_alpha() => extra,
+                Channel::A | Channel::X => {
+                    plan.fill = Some(i);
+                    extra
+                }
+                Channel::Zero => extra,
+            };
+        }
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Formats that parse back to a format with the same byte order, only the padding may
+    /// differ.
+    fn same_layout(a: PixelFormat, b: PixelFormat) -> bool {
+        a.plan_to(b).order == [0, 1, 2, 3]
+    }
+
+    #[test]
+    fn test_ffmpeg_names() {
+        for format in PixelFormat::ALL {
+            let parsed = PixelFormat::from_ffmpeg_name(format.ffmpeg_name()).unwrap();
+            assert!(same_layout(format, parsed), "{format:?} -> {parsed:?}");
+        }
+        assert_eq!(
+            PixelFormat::from_ffmpeg_name("0rgb"),
+            Some(PixelFormat::Xrgb)
+        );
+        assert_eq!(PixelFormat::from_ffmpeg_name("RGBA"), None);
+        assert_eq!(PixelFormat::from_ffmpeg_name("rgb24"), None);
+    }
+
+    #[test]
+    fn test_gstreamer_names() {
+        for format in PixelFormat::ALL {
+            let parsed = PixelFormat::from_gstreamer_name(format.gstreamer_name()).unwrap();
+            assert!(same_layout(format, parsed), "{format:?} -> {parsed:?}");
+        }
+        assert_eq!(
+            PixelFormat::from_gstreamer_name("BGRx"),
+            Some(PixelFormat::Bgrx)
+        );
+        assert_eq!(PixelFormat::from_gstreamer_name("rgba"), None);
+        assert_eq!(PixelFormat::from_gstreamer_name("RGB"), None);
+    }
+}
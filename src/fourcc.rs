@@ -0,0 +1,192 @@
+//! DRM and Wayland (`wl_shm`) fourcc format codes.
+//!
+//! DRM formats are named after the channels of a little endian packed pixel from the most to
+//! the least significant bit, so `XRGB8888` is stored as `B, G, R, X` in memory, which is the
+//! opposite of the byte order names used by the rest of the crate. [`pixel_format`] takes care
+//! of the translation.
+
+use crate::{PixelFormat, SwizzleError};
+
+/// Build a fourcc code from its four characters, like `fourcc_code` in `drm_fourcc.h`.
+pub const fn fourcc_code(a: u8, b: u8, c: u8, d: u8) -> u32 {
+    a as u32 | (b as u32) << 8 | (c as u32) << 16 | (d as u32) << 24
+}
+
+/// `[31:0] x:R:G:B 8:8:8:8 little endian`
+pub const XRGB8888: u32 = fourcc_code(b'X', b'R', b'2', b'4');
+/// `[31:0] x:B:G:R 8:8:8:8 little endian`
+pub const XBGR8888: u32 = fourcc_code(b'X', b'B', b'2', b'4');
+/// `[31:0] R:G:B:x 8:8:8:8 little endian`
+pub const RGBX8888: u32 = fourcc_code(b'R', b'X', b'2', b'4');
+/// `[31:0] B:G:R:x 8:8:8:8 little endian`
+pub const BGRX8888: u32 = fourcc_code(b'B', b'X', b'2', b'4');
+/// `[31:0] A:R:G:B 8:8:8:8 little endian`
+pub const ARGB8888: u32 = fourcc_code(b'A', b'R', b'2', b'4');
+/// `[31:0] A:B:G:R 8:8:8:8 little endian`
+pub const ABGR8888: u32 = fourcc_code(b'A', b'B', b'2', b'4');
+/// `[31:0] R:G:B:A 8:8:8:8 little endian`
+pub const RGBA8888: u32 = fourcc_code(b'R', b'A', b'2', b'4');
+/// `[31:0] B:G:R:A 8:8:8:8 little endian`
+pub const BGRA8888: u32 = fourcc_code(b'B', b'A', b'2', b'4');
+
+/// `WL_SHM_FORMAT_ARGB8888`, `wl_shm` uses its own codes for the two formats every compositor
+/// must support.
+pub const WL_SHM_ARGB8888: u32 = 0;
+/// `WL_SHM_FORMAT_XRGB8888`
+pub const WL_SHM_XRGB8888: u32 = 1;
+
+/// Returns the byte order of pixels in the DRM or `wl_shm` format `code`, or `None` if the
+/// format is not a supported 4 channel, 8 bits per channel format.
+///
+/// ```rust
+/// use image_swizzle::{fourcc, PixelFormat};
+/// assert_eq!(fourcc::pixel_format(fourcc::XRGB8888), Some(PixelFormat::Bgrx));
+/// assert_eq!(fourcc::pixel_format(fourcc::ABGR8888), Some(PixelFormat::Rgba));
+/// ```
+pub const fn pixel_format(code: u32) -> Option<PixelFormat> {
+    Some(match code {
+        XRGB8888 | WL_SHM_XRGB8888 => PixelFormat::Bgrx,
+        XBGR8888 => PixelFormat::Rgbx,
+        RGBX8888 => PixelFormat::Xbgr,
+        BGRX8888 => PixelFormat::Xrgb,
+        ARGB8888 | WL_SHM_ARGB8888 => PixelFormat::Bgra,
+        ABGR8888 => PixelFormat::Rgba,
+        RGBA8888 => PixelFormat::Abgr,
+        BGRA8888 => PixelFormat::Argb,
+        _ => return None,
+    })
+}
+
+/// Returns the DRM format code of pixels with the byte order `format`.
+pub const fn drm_format(format: PixelFormat) -> u32 {
+    match format {
+        PixelFormat::Rgba => ABGR8888,
+        PixelFormat::Bgra => ARGB8888,
+        PixelFormat::Argb => BGRA8888,
+        PixelFormat::Abgr => RGBA8888,
+        PixelFormat::Rgb0 | PixelFormat::Rgbx => XBGR8888,
+        PixelFormat::Bgr0 | PixelFormat::Bgrx => XRGB8888,
+        PixelFormat::Xrgb => BGRX8888,
+        PixelFormat::Xbgr => RGBX8888,
+    }
+}
+
+fn lookup(code: u32) -> Result<PixelFormat, SwizzleError> {
+    pixel_format(code).ok_or(SwizzleError::UnknownFourcc { code })
+}
+
+/// Convert `src` in the DRM or `wl_shm` format `src_code` to `dst_code` and store the result to
+/// `dst`.
+///
+/// ```rust
+/// use image_swizzle::fourcc;
+/// let xrgb8888 = 0x00010203u32.to_le_bytes();
+/// let mut abgr8888 = [0; 4];
+/// fourcc::convert_fourcc(&xrgb8888, fourcc::XRGB8888, &mut abgr8888, fourcc::ABGR8888).unwrap();
+/// assert_eq!(u32::from_le_bytes(abgr8888), 0xff030201);
+/// ```
+///
+/// Returns an error if either code is not supported, if `src.len` is not multiple of a 4 or if
+/// `dst.len` is not equal to `src.len`.
+pub fn convert_fourcc(
+    src: &[u8],
+    src_code: u32,
+    dst: &mut [u8],
+    dst_code: u32,
+) -> Result<(), SwizzleError> {
+    crate::convert(src, lookup(src_code)?, dst, lookup(dst_code)?)
+}
+
+/// Convert `src` from the DRM or `wl_shm` format `src_code` to `dst_code` while overwriting the
+/// old data in `src`.
+///
+/// Returns an error if either code is not supported or if `src.len` is not multiple of a 4.
+pub fn convert_fourcc_inplace(
+    src: &mut [u8],
+    src_code: u32,
+    dst_code: u32,
+) -> Result<(), SwizzleError> {
+    crate::convert_inplace(src, lookup(src_code)?, lookup(dst_code)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every supported code with its name and channels from the most significant byte of a
+    /// little endian `u32`, as written in `drm_fourcc.h`.
+    const CODES: [(u32, &[u8; 4], &str); 10] = [
+        (XRGB8888, b"XR24", "xRGB"),
+        (XBGR8888, b"XB24", "xBGR"),
+        (RGBX8888, b"RX24", "RGBx"),
+        (BGRX8888, b"BX24", "BGRx"),
+        (ARGB8888, b"AR24", "ARGB"),
+        (ABGR8888, b"AB24", "ABGR"),
+        (RGBA8888, b"RA24", "RGBA"),
+        (BGRA8888, b"BA24", "BGRA"),
+        (WL_SHM_XRGB8888, b"\x01\0\0\0", "xRGB"),
+        (WL_SHM_ARGB8888, b"\0\0\0\0", "ARGB"),
+    ];
+
+    /// Packs the channels in `layout` into a little endian `u32` with R = 1, G = 2, B = 3,
+    /// A = 4 and padding = 9.
+    fn pixel(layout: &str) -> [u8; 4] {
+        let mut px = 0u32;
+        for c in layout.chars() {
+            px = px << 8
+                | match c {
+                    'R' => 1,
+                    'G' => 2,
+                    'B' => 3,
+                    'A' => 4,
+                    _ => 9,
+                };
+        }
+        px.to_le_bytes()
+    }
+
+    #[test]
+    fn test_codes() {
+        for (code, name, _) in CODES {
+            assert_eq!(code, u32::from_le_bytes(*name));
+        }
+    }
+
+    #[test]
+    fn test_convert_fourcc_table() {
+        for (src_code, _, src_layout) in CODES {
+            for (dst_code, _, dst_layout) in CODES {
+                let src = pixel(src_layout).repeat(37);
+                let mut dst = vec![0; src.len()];
+                convert_fourcc(&src, src_code, &mut dst, dst_code).unwrap();
+
+                let mut expected = pixel(dst_layout).map(|b| if b == 9 { 255 } else { b });
+                if !src_layout.contains('A') {
+                    expected = expected.map(|b| if b == 4 { 255 } else { b });
+                }
+                assert_eq!(dst, expected.repeat(37), "{src_layout} -> {dst_layout}");
+
+                let mut inplace = src.clone();
+                convert_fourcc_inplace(&mut inplace, src_code, dst_code).unwrap();
+                assert_eq!(inplace, dst);
+            }
+        }
+    }
+
+    #[test]
+    fn test_drm_format_roundtrip() {
+        for (code, _, _) in &CODES[..8] {
+            assert_eq!(drm_format(pixel_format(*code).unwrap()), *code);
+        }
+    }
+
+    #[test]
+    fn test_unknown_fourcc() {
+        let nv12 = fourcc_code(b'N', b'V', b'1', b'2');
+        assert_eq!(pixel_format(nv12), None);
+        assert_eq!(
+            convert_fourcc(&[0; 4], XRGB8888, &mut [0; 4], nv12),
+            Err(SwizzleError::UnknownFourcc { code: nv12 })
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use crate::error::check_rows;
+use crate::strided::{for_each_row, for_each_row_inplace};
+use crate::{PixelFormat, SwizzleError};
+
+const BYTES_PER_PIXEL: usize = 4;
+
+/// Rectangle of pixels inside an image, `x` and `y` are the coordinates of the top left
+/// corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the offset of the first byte of the rectangle in an image with rows `stride`
+    /// bytes apart, or an error if the rectangle does not fit in a `width`x`height` image.
+    fn offset(&self, width: usize, height: usize, stride: usize) -> Result<usize, SwizzleError> {
+        let fits = |start: usize, len: usize, max: usize| {
+            start.checked_add(len).is_some_and(|end| end <= max)
+        };
+        if !fits(self.x, self.width, width) || !fits(self.y, self.height, height) {
+            return Err(SwizzleError::RegionOutOfBounds {
+                region: *self,
+                width,
+                height,
+            });
+        }
+        Ok(self.y * stride + self.x * BYTES_PER_PIXEL)
+    }
+}
+
+/// Borrowed image with known dimensions, row stride and [`PixelFormat`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImageView<'a> {
+    data: &'a [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    format: PixelFormat,
+}
+
+/// Mutably borrowed image with known dimensions, row stride and [`PixelFormat`].
+#[derive(Debug)]
+pub struct ImageViewMut<'a> {
+    data: &'a mut [u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    format: PixelFormat,
+}
+
+impl<'a> ImageView<'a> {
+    /// Create a view of `height` rows of `width` pixels where rows start every `stride` bytes.
+    ///
+    /// Returns an error if `stride` is smaller than a row of pixels or if `data` is too small
+    /// for the image.
+    pub fn new(
+        data: &'a [u8],
+        width: usize,
+        height: usize,
+        stride: usize,
+        format: PixelFormat,
+    ) -> Result<Self, SwizzleError> {
+        check_rows(
+            data.len(),
+            stride,
+            width.saturating_mul(BYTES_PER_PIXEL),
+            height,
+        )?;
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+            format,
+        })
+    }
+
+    /// Create a view of an image without row padding.
+    pub fn packed(
+        data: &'a [u8],
+        width: usize,
+        height: usize,
+        format: PixelFormat,
+    ) -> Result<Self, SwizzleError> {
+        let stride = width.saturating_mul(BYTES_PER_PIXEL);
+        Self::new(data, width, height, stride, format)
+    }
+
+    /// Create a view of the pixels inside `rect`.
+    ///
+    /// Returns an error if `rect` does not fit inside the image.
+    pub fn region(&self, rect: Rect) -> Result<ImageView<'a>, SwizzleError> {
+        let offset = rect.offset(self.width, self.height, self.stride)?;
+        Ok(ImageView {
+            data: &self.data[offset.min(self.data.len())..],
+            width: rect.width,
+            height: rect.height,
+            stride: self.stride,
+            format: self.format,
+        })
+    }
+
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+}
+
+impl<'a> ImageViewMut<'a> {
+    /// Create a view of `height` rows of `width` pixels where rows start every `stride` bytes.
+    ///
+    /// Returns an error if `stride` is smaller than a row of pixels or if `data` is too small
+    /// for the image.
+    pub fn new(
+        data: &'a mut [u8],
+        width: usize,
+        height: usize,
+        stride: usize,
+        format: PixelFormat,
+    ) -> Result<Self, SwizzleError> {
+        check_rows(
+            data.len(),
+            stride,
+            width.saturating_mul(BYTES_PER_PIXEL),
+            height,
+        )?;
+        Ok(Self {
+            data,
+            width,
+            height,
+            stride,
+            format,
+        })
+    }
+
+    /// Create a view of an image without row padding.
+    pub fn packed(
+        data: &'a mut [u8],
+        width: usize,
+        height: usize,
+        format: PixelFormat,
+    ) -> Result<Self, SwizzleError> {
+        let stride = width.saturating_mul(BYTES_PER_PIXEL);
+        Self::new(data, width, height, stride, format)
+    }
+
+    /// Reborrow as an immutable view.
+    pub fn as_view(&self) -> ImageView<'_> {
+        ImageView {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+            format: self.format,
+        }
+    }
+
+    /// Create a mutable view of the pixels inside `rect`.
+    ///
+    /// Returns an error if `rect` does not fit inside the image.
+    pub fn region_mut(&mut self, rect: Rect) -> Result<ImageViewMut<'_>, SwizzleError> {
+        let offset = rect.offset(self.width, self.height, self.stride)?;
+        let len = self.data.len();
+        Ok(ImageViewMut {
+            data: &mut self.data[offset.min(len)..],
+            width: rect.width,
+            height: rect.height,
+            stride: self.stride,
+            format: self.format,
+        })
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        self.data
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub fn format(&self) -> PixelFormat {
+        self.format
+    }
+
+    /// Convert the image to `format` in place and update the format of the view.
+    ///
+    /// ```rust
+    /// use image_swizzle::{ImageViewMut, PixelFormat};
+    /// let mut data = [1, 2, 3, 0, 0xaa, 0xaa];
+    /// let mut img = ImageViewMut::new(&mut data, 1, 1, 6, PixelFormat::Rgb0).unwrap();
+    /// img.convert_to(PixelFormat::Bgra).unwrap();
+    /// assert_eq!(img.format(), PixelFormat::Bgra);
+    /// assert_eq!(data, [3, 2, 1, 255, 0xaa, 0xaa]);
+    /// ```
+    pub fn convert_to(&mut self, format: PixelFormat) -> Result<(), SwizzleError> {
+        let src_fmt = self.format;
+        let mut result = Ok(());
+        for_each_row_inplace(
+            self.data,
+            self.stride,
+            self.width.saturating_mul(BYTES_PER_PIXEL),
+            self.height,
+            |row| {
+                if result.is_ok() {
+                    result = crate::convert_inplace(row, src_fmt, format);
+                }
+            },
+        )?;
+        result?;
+        self.format = format;
+        Ok(())
+    }
+}
+
+/// Convert the pixels of `src` to the format of `dst` and store the result to `dst`.
+///
+/// Padding bytes at the end of the rows of `dst` are left untouched.
+///
+/// ```rust
+/// use image_swizzle::{convert_into, ImageView, ImageViewMut, PixelFormat};
+/// let rgba = [1, 2, 3, 255, 4, 5, 6, 255];
+/// let mut bgrx = [0; 12];
+/// let src = ImageView::packed(&rgba, 1, 2, PixelFormat::Rgba).unwrap();
+/// let mut dst = ImageViewMut::new(&mut bgrx, 1, 2, 6, PixelFormat::Bgrx).unwrap();
+/// convert_into(&src, &mut dst).unwrap();
+/// assert_eq!(bgrx, [3, 2, 1, 255, 0, 0, 6, 5, 4, 255, 0, 0]);
+/// ```
+///
+/// Returns an error if the images do not have the same dimensions.
+pub fn convert_into(src: &ImageView, dst: &mut ImageViewMut) -> Result<(), SwizzleError> {
+    if (src.width, src.height) != (dst.width, dst.height) {
+        return Err(SwizzleError::DimensionMismatch {
+            src: (src.width, src.height),
+            dst: (dst.width, dst.height),
+        });
+    }
+
+    let (src_fmt, dst_fmt) = (src.format, dst.format);
+    let mut result = Ok(());
+    for_each_row(
+        src.data,
+        src.stride,
+        dst.data,
+        dst.stride,
+        src.width.saturating_mul(BYTES_PER_PIXEL),
+        src.height,
+        |src, dst| {
+            if result.is_ok() {
+                result = crate::convert(src, src_fmt, dst, dst_fmt);
+            }
+        },
+    )?;
+    result
+}
+
+/// Convert the pixels of `src` inside `rect` to the format of `dst` and store the result to
+/// `dst` with the top left corner at (`dst_x`, `dst_y`).
+///
+/// Only the bytes of `dst` inside the destination rectangle are written.
+///
+/// ```rust
+/// use image_swizzle::{convert_region, ImageView, ImageViewMut, PixelFormat, Rect};
+/// let rgba: Vec<u8> = (0..4 * 4 * 4).collect();
+/// let mut bgra = [0; 3 * 3 * 4];
+/// let src = ImageView::packed(&rgba, 4, 4, PixelFormat::Rgba).unwrap();
+/// let mut dst = ImageViewMut::packed(&mut bgra, 3, 3, PixelFormat::Bgra).unwrap();
+/// convert_region(&src, Rect::new(2, 1, 1, 2), &mut dst, 1, 0).unwrap();
+/// assert_eq!(&bgra[4..8], [26, 25, 24, 27]);
+/// assert_eq!(&bgra[16..20], [42, 41, 40, 43]);
+/// assert!(bgra[..4].iter().chain(&bgra[8..16]).chain(&bgra[20..]).all(|&b| b == 0));
+/// ```
+///
+/// Returns an error if `rect` does not fit inside `src` or if the destination rectangle does not
+/// fit inside `dst`.
+pub fn convert_region(
+    src: &ImageView,
+    rect: Rect,
+    dst: &mut ImageViewMut,
+    dst_x: usize,
+    dst_y: usize,
+) -> Result<(), SwizzleError> {
+    let src = src.region(rect)?;
+    let mut dst = dst.region_mut(Rect::new(dst_x, dst_y, rect.width, rect.height))?;
+    convert_into(&src, &mut dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_validation() {
+        let data = [0; 40];
+        assert!(ImageView::new(&data, 2, 3, 16, PixelFormat::Rgba).is_ok());
+        assert!(ImageView::packed(&data, 10, 1, PixelFormat::Rgba).is_ok());
+        assert_eq!(
+            ImageView::new(&data, 2, 3, 4, PixelFormat::Rgba).unwrap_err(),
+            SwizzleError::StrideTooSmall { stride: 4, min: 8 }
+        );
+        assert_eq!(
+            ImageView::packed(&data, 11, 1, PixelFormat::Rgba).unwrap_err(),
+            SwizzleError::BufferTooSmall { len: 40, min: 44 }
+        );
+    }
+
+    #[test]
+    fn test_convert_into_strided() {
+        let (width, height) = (19, 3);
+        let src_stride = width * 4 + 4;
+        let dst_stride = width * 4 + 8;
+        let mut src_data = vec![0xaa; src_stride * height];
+        for row in src_data.chunks_exact_mut(src_stride) {
+            for px in row[..width * 4].chunks_exact_mut(4) {
+                px.copy_from_slice(&[0, 1, 2, 3]);
+            }
+        }
+        let mut dst_data = vec![0xbb; dst_stride * height];
+
+        let src = ImageView::new(&src_data, width, height, src_stride, PixelFormat::Xrgb).unwrap();
+        let mut dst =
+            ImageViewMut::new(&mut dst_data, width, height, dst_stride, PixelFormat::Bgra).unwrap();
+        convert_into(&src, &mut dst).unwrap();
+
+        for row in dst_data.chunks_exact(dst_stride) {
+            for px in row[..width * 4].chunks_exact(4) {
+                assert_eq!(px, [3, 2, 1, 255]);
+            }
+            assert!(row[width * 4..].iter().all(|&b| b == 0xbb));
+        }
+    }
+
+    #[test]
+    fn test_region() {
+        let data: Vec<u8> = (0..5 * 3 * 4).collect();
+        let img = ImageView::packed(&data, 5, 3, PixelFormat::Rgba).unwrap();
+        let region = img.region(Rect::new(1, 1, 4, 2)).unwrap();
+        assert_eq!(
+            (region.width(), region.height(), region.stride()),
+            (4, 2, 20)
+        );
+        assert_eq!(region.data()[0], 24);
+        assert_eq!(region.data().len(), 36);
+
+        let empty = img.region(Rect::new(5, 3, 0, 0)).unwrap();
+        assert!(empty.data().is_empty());
+
+        for rect in [
+            Rect::new(2, 0, 4, 1),
+            Rect::new(0, 3, 1, 1),
+            Rect::new(usize::MAX, 0, 2, 1),
+        ] {
+            assert_eq!(
+                img.region(rect).unwrap_err(),
+                SwizzleError::RegionOutOfBounds {
+                    region: rect,
+                    width: 5,
+                    height: 3
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_region() {
+        let (width, height) = (40, 12);
+        let src_stride = width * 4 + 16;
+        let src_data: Vec<u8> = (0..src_stride * height).map(|i| i as u8).collect();
+        let src = ImageView::new(&src_data, width, height, src_stride, PixelFormat::Argb).unwrap();
+
+        let (dst_width, dst_height) = (30, 10);
+        let mut dst_data = vec![0xbb; dst_width * 4 * dst_height];
+        let mut dst =
+            ImageViewMut::packed(&mut dst_data, dst_width, dst_height, PixelFormat::Rgba).unwrap();
+
+        let rect = Rect::new(3, 2, 21, 5);
+        let (dst_x, dst_y) = (7, 4);
+        assert!(matches!(
+            convert_region(&src, rect, &mut dst, 10, 4),
+            Err(SwizzleError::RegionOutOfBounds { .. })
+        ));
+        convert_region(&src, rect, &mut dst, dst_x, dst_y).unwrap();
+
+        for y in 0..dst_height {
+            for x in 0..dst_width {
+                let px = &dst_data[(y * dst_width + x) * 4..][..4];
+                let inside = (dst_x..dst_x + rect.width).contains(&x)
+                    && (dst_y..dst_y + rect.height).contains(&y);
+                if inside {
+                    let (sx, sy) = (x - dst_x + rect.x, y - dst_y + rect.y);
+                    let s = &src_data[sy * src_stride + sx * 4..][..4];
+                    assert_eq!(px, [s[1], s[2], s[3], s[0]]);
+                } else {
+                    assert_eq!(px, [0xbb; 4]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_into_dimension_mismatch() {
+        let src_data = [0; 16];
+        let mut dst_data = [0; 16];
+        let src = ImageView::packed(&src_data, 2, 2, PixelFormat::Rgba).unwrap();
+        let mut dst = ImageViewMut::packed(&mut dst_data, 4, 1, PixelFormat::Bgra).unwrap();
+        assert_eq!(
+            convert_into(&src, &mut dst),
+            Err(SwizzleError::DimensionMismatch {
+                src: (2, 2),
+                dst: (4, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_convert_huge_empty_image() {
+        let mut img =
+            ImageViewMut::new(&mut [], usize::MAX, 0, usize::MAX, PixelFormat::Rgba).unwrap();
+        img.convert_to(PixelFormat::Bgra).unwrap();
+        assert_eq!(img.format(), PixelFormat::Bgra);
+
+        let src = ImageView::new(&[], usize::MAX, 0, usize::MAX, PixelFormat::Rgba).unwrap();
+        convert_into(&src, &mut img).unwrap();
+    }
+}
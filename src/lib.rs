@@ -33,6 +33,30 @@ pub mod sisd;
 
 pub(crate) mod common;
 
+pub mod fourcc;
+pub mod packed;
+pub mod rfb;
+pub mod rgb565;
+pub mod rgba16;
+pub mod rgbaf16;
+pub mod rgbaf32;
+
+mod convert;
+mod error;
+mod format;
+mod image;
+mod masks;
+mod resize;
+mod strided;
+
+pub use convert::{convert, convert_inplace};
+pub use error::SwizzleError;
+pub use format::{PixelFormat, PixelFormat24};
+pub use image::{convert_into, convert_region, ImageView, ImageViewMut, Rect};
+pub use masks::{convert_from_masks, convert_to_masks, ByteOrder, ChannelMasks};
+pub use resize::{expand_vec, shrink_vec};
+pub use strided::*;
+
 /// Convert RGBA data to BGRA while overwriting the old RGBA data in `src`.
 ///
 /// ```rust
@@ -67,6 +91,46 @@ pub fn rgba32_to_rgba_inplace(src: &mut [u8]) {
     sisd::rgba32_to_rgba_inplace(src);
 }
 
+/// Convert RGBA32 data, native endian `u32` pixels laid out as `0xRRGGBBAA`, to BGRA and
+/// store the result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::rgba32_to_bgra;
+/// let rgba32 = 0x010203ffu32.to_ne_bytes();
+/// let mut bgra = [0; 4];
+/// rgba32_to_bgra(&rgba32, &mut bgra);
+/// assert_eq!(bgra, [3, 2, 1, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+#[inline]
+pub fn rgba32_to_bgra(src: &[u8], dst: &mut [u8]) {
+    #[cfg(feature = "nightly")]
+    simd::rgba32_to_bgra(src, dst);
+    #[cfg(not(feature = "nightly"))]
+    sisd::rgba32_to_bgra(src, dst);
+}
+
+/// Convert RGBA32 data, native endian `u32` pixels laid out as `0xRRGGBBAA`, to RGBA and
+/// store the result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::rgba32_to_rgba;
+/// let rgba32 = 0x010203ffu32.to_ne_bytes();
+/// let mut rgba = [0; 4];
+/// rgba32_to_rgba(&rgba32, &mut rgba);
+/// assert_eq!(rgba, [1, 2, 3, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+#[inline]
+pub fn rgba32_to_rgba(src: &[u8], dst: &mut [u8]) {
+    #[cfg(feature = "nightly")]
+    simd::rgba32_to_rgba(src, dst);
+    #[cfg(not(feature = "nightly"))]
+    sisd::rgba32_to_rgba(src, dst);
+}
+
 /// Convert RGBA data to BGRA and store the result to `dst`.
 ///
 /// ```rust
@@ -271,27 +335,542 @@ pub fn bgr0_to_rgbx(src: &[u8], dst: &mut [u8]) {
     sisd::bgr0_to_rgbx(src, dst);
 }
 
-/// Convert ARGB data to RGBA while overwriting the old ARGB data in `src`.
+/// Convert RGB24 data to BGR24 while overwriting the old data in `src`.
 ///
 /// ```rust
-/// use image_swizzle::bgr0_to_rgbx_inplace;
-/// let mut bgr0 = [3, 2, 1, 0];
-/// bgr0_to_rgbx_inplace(&mut bgr0);
-/// assert_eq!(bgr0, [1, 2, 3, 255]);
+/// use image_swizzle::rgb24_to_bgr24_inplace;
+/// let mut rgb = [1, 2, 3];
+/// rgb24_to_bgr24_inplace(&mut rgb);
+/// assert_eq!(rgb, [3, 2, 1]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 3.
+#[inline]
+pub fn rgb24_to_bgr24_inplace(src: &mut [u8]) {
+    #[cfg(feature = "nightly")]
+    simd::rgb24_to_bgr24_inplace(src);
+    #[cfg(not(feature = "nightly"))]
+    sisd::rgb24_to_bgr24_inplace(src);
+}
+
+/// Convert RGB24 data to BGR24 and store the result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::rgb24_to_bgr24;
+/// let rgb = [1, 2, 3];
+/// let mut bgr = [0; 3];
+/// rgb24_to_bgr24(&rgb, &mut bgr);
+/// assert_eq!(bgr, [3, 2, 1]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 3 or if `dst.len` is not equal to `src.len`.
+#[inline]
+pub fn rgb24_to_bgr24(src: &[u8], dst: &mut [u8]) {
+    #[cfg(feature = "nightly")]
+    simd::rgb24_to_bgr24(src, dst);
+    #[cfg(not(feature = "nightly"))]
+    sisd::rgb24_to_bgr24(src, dst);
+}
+
+/// Convert BGR24 data to RGB24 while overwriting the old data in `src`.
+///
+/// ```rust
+/// use image_swizzle::bgr24_to_rgb24_inplace;
+/// let mut bgr = [3, 2, 1];
+/// bgr24_to_rgb24_inplace(&mut bgr);
+/// assert_eq!(bgr, [1, 2, 3]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 3.
+#[inline]
+pub fn bgr24_to_rgb24_inplace(src: &mut [u8]) {
+    #[cfg(feature = "nightly")]
+    simd::bgr24_to_rgb24_inplace(src);
+    #[cfg(not(feature = "nightly"))]
+    sisd::bgr24_to_rgb24_inplace(src);
+}
+
+/// Convert BGR24 data to RGB24 and store the result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::bgr24_to_rgb24;
+/// let bgr = [3, 2, 1];
+/// let mut rgb = [0; 3];
+/// bgr24_to_rgb24(&bgr, &mut rgb);
+/// assert_eq!(rgb, [1, 2, 3]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 3 or if `dst.len` is not equal to `src.len`.
+#[inline]
+pub fn bgr24_to_rgb24(src: &[u8], dst: &mut [u8]) {
+    #[cfg(feature = "nightly")]
+    simd::bgr24_to_rgb24(src, dst);
+    #[cfg(not(feature = "nightly"))]
+    sisd::bgr24_to_rgb24(src, dst);
+}
+
+macro_rules! impl_conversions {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*) => {
+        $(
+            impl_conversions!(@fn $name, $inplace, $src => $dst, [$($order),*] $(, $lane)?);
+        )*
+    };
+    (@fn $name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*]) => {
+        impl_conversions!(@impl $name, $inplace, $src => $dst, [$($order),*], |i| src[i]);
+    };
+    (@fn $name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*], $lane:literal) => {
+        impl_conversions!(
+            @impl $name, $inplace, $src => $dst, [$($order),*],
+            |i| if i == $lane { 255 } else { src[i] }
+        );
+    };
+    (@impl $name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*], $map:expr) => {
+        #[doc = concat!(
+            "Convert [`PixelFormat::", stringify!($src), "`] data to [`PixelFormat::",
+            stringify!($dst), "`] while overwriting the old data in `src`."
+        )]
+        ///
+        /// ```rust
+        #[doc = concat!("use image_swizzle::", stringify!($inplace), ";")]
+        /// let src = [1, 2, 3, 4];
+        /// let mut px = src;
+        #[doc = concat!(stringify!($inplace), "(&mut px);")]
+        #[doc = concat!(
+            "assert_eq!(px, ", stringify!([$($order),*]), ".map(", stringify!($map), "));"
+        )]
+        /// ```
+        ///
+        /// Panics if `src.len` is not multiple of a 4.
+        #[inline]
+        pub fn $inplace(src: &mut [u8]) {
+            #[cfg(feature = "nightly")]
+            simd::$inplace(src);
+            #[cfg(not(feature = "nightly"))]
+            sisd::$inplace(src);
+        }
+
+        #[doc = concat!(
+            "Convert [`PixelFormat::", stringify!($src), "`] data to [`PixelFormat::",
+            stringify!($dst), "`] and store the result to `dst`."
+        )]
+        ///
+        /// ```rust
+        #[doc = concat!("use image_swizzle::", stringify!($name), ";")]
+        /// let src = [1, 2, 3, 4];
+        /// let mut dst = [0; 4];
+        #[doc = concat!(stringify!($name), "(&src, &mut dst);")]
+        #[doc = concat!(
+            "assert_eq!(dst, ", stringify!([$($order),*]), ".map(", stringify!($map), "));"
+        )]
+        /// ```
+        ///
+        /// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+        #[inline]
+        pub fn $name(src: &[u8], dst: &mut [u8]) {
+            #[cfg(feature = "nightly")]
+            simd::$name(src, dst);
+            #[cfg(not(feature = "nightly"))]
+            sisd::$name(src, dst);
+        }
+    };
+}
+
+common::for_each_conversion!(impl_conversions);
+
+macro_rules! impl_expansions {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $alpha:ident)?;)*) => {
+        $(
+            impl_expansions!(@fn $name, $src, $dst $(, $alpha)?);
+        )*
+    };
+    (@fn $name:ident, $src:ident, $dst:ident, alpha) => {
+        #[doc = concat!(
+            "Convert 3 byte ", stringify!($src), " pixels to [`PixelFormat::", stringify!($dst),
+            "`] with every alpha channel set to `alpha` and store the result to `dst`."
+        )]
+        ///
+        /// Panics if `src.len` is not multiple of a 3 or if `dst.len` is not equal to
+        /// `src.len / 3 * 4`.
+        #[inline]
+        pub fn $name(src: &[u8], dst: &mut [u8], alpha: u8) {
+            #[cfg(feature = "nightly")]
+            simd::$name(src, dst, alpha);
+            #[cfg(not(feature = "nightly"))]
+            sisd::$name(src, dst, alpha);
+        }
+    };
+    (@fn $name:ident, $src:ident, $dst:ident) => {
+        #[doc = concat!(
+            "Convert 3 byte ", stringify!($src), " pixels to [`PixelFormat::", stringify!($dst),
+            "`] and store the result to `dst`."
+        )]
+        ///
+        /// Panics if `src.len` is not multiple of a 3 or if `dst.len` is not equal to
+        /// `src.len / 3 * 4`.
+        #[inline]
+        pub fn $name(src: &[u8], dst: &mut [u8]) {
+            #[cfg(feature = "nightly")]
+            simd::$name(src, dst);
+            #[cfg(not(feature = "nightly"))]
+            sisd::$name(src, dst);
+        }
+    };
+}
+
+common::for_each_expansion!(impl_expansions);
+
+macro_rules! impl_packings {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+        $(
+            #[doc = concat!(
+                "Convert [`PixelFormat::", stringify!($src), "`] data to 3 byte ",
+                stringify!($dst), " pixels and store the result to `dst`."
+            )]
+            ///
+            /// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to
+            /// `src.len / 4 * 3`.
+            #[inline]
+            pub fn $name(src: &[u8], dst: &mut [u8]) {
+                #[cfg(feature = "nightly")]
+                simd::$name(src, dst);
+                #[cfg(not(feature = "nightly"))]
+                sisd::$name(src, dst);
+            }
+        )*
+    };
+}
+
+common::for_each_packing!(impl_packings);
+
+/// Set channel `lane` of every pixel of 4 channel data to `value` while overwriting the old
+/// data in `src`.
+///
+/// This is [`rgb0_to_rgbx_inplace`] with any fill value and padding position, e.g. lane 0 for
+/// `0rgb` data.
+///
+/// ```rust
+/// use image_swizzle::fill_lane_inplace;
+/// let mut xrgb = [0, 1, 2, 3];
+/// fill_lane_inplace(&mut xrgb, 0, 128);
+/// assert_eq!(xrgb, [128, 1, 2, 3]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4 or if `lane` is greater than 3.
+#[inline]
+pub fn fill_lane_inplace(src: &mut [u8], lane: usize, value: u8) {
+    #[cfg(feature = "nightly")]
+    simd::fill_lane_inplace(src, lane, value);
+    #[cfg(not(feature = "nightly"))]
+    sisd::fill_lane_inplace(src, lane, value);
+}
+
+/// Copy 4 channel data to `dst` with channel `lane` of every pixel set to `value`.
+///
+/// ```rust
+/// use image_swizzle::fill_lane;
+/// let rgb0 = [1, 2, 3, 0];
+/// let mut rgbx = [0; 4];
+/// fill_lane(&rgb0, &mut rgbx, 3, 0);
+/// assert_eq!(rgbx, [1, 2, 3, 0]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// `lane` is greater than 3.
+#[inline]
+pub fn fill_lane(src: &[u8], dst: &mut [u8], lane: usize, value: u8) {
+    #[cfg(feature = "nightly")]
+    simd::fill_lane(src, dst, lane, value);
+    #[cfg(not(feature = "nightly"))]
+    sisd::fill_lane(src, dst, lane, value);
+}
+
+/// Set input channel `lane` to `value` and reorder the channels of 4 channel data according to
+/// `order` while overwriting the old data in `src`.
+///
+/// The filled lane is a lane of the input, so it ends up wherever `order` puts it.
+///
+/// ```rust
+/// use image_swizzle::swizzle4_fill_inplace;
+/// let mut rgb0 = [1, 2, 3, 0];
+/// swizzle4_fill_inplace(&mut rgb0, [2, 1, 0, 3], 3, 128);
+/// assert_eq!(rgb0, [3, 2, 1, 128]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if any index in `order` is greater than 3 or if
+/// `lane` is greater than 3.
+#[inline]
+pub fn swizzle4_fill_inplace(src: &mut [u8], order: [u8; 4], lane: usize, value: u8) {
+    #[cfg(feature = "nightly")]
+    simd::swizzle4_fill_inplace(src, order, lane, value);
+    #[cfg(not(feature = "nightly"))]
+    sisd::swizzle4_fill_inplace(src, order, lane, value);
+}
+
+/// Set input channel `lane` to `value`, reorder the channels of 4 channel data according to
+/// `order` and store the result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::swizzle4_fill;
+/// let xrgb = [0, 1, 2, 3];
+/// let mut bgra = [0; 4];
+/// swizzle4_fill(&xrgb, &mut bgra, [3, 2, 1, 0], 0, 128);
+/// assert_eq!(bgra, [3, 2, 1, 128]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len`, if any
+/// index in `order` is greater than 3 or if `lane` is greater than 3.
+#[inline]
+pub fn swizzle4_fill(src: &[u8], dst: &mut [u8], order: [u8; 4], lane: usize, value: u8) {
+    #[cfg(feature = "nightly")]
+    simd::swizzle4_fill(src, dst, order, lane, value);
+    #[cfg(not(feature = "nightly"))]
+    sisd::swizzle4_fill(src, dst, order, lane, value);
+}
+
+/// Reorder the channels of 4 channel data according to `order` while overwriting the old data
+/// in `src`.
+///
+/// Channel `i` of every output pixel is taken from channel `order[i]` of the input pixel, so
+/// the same input channel may be used more than once.
+///
+/// ```rust
+/// use image_swizzle::swizzle4_inplace;
+/// let mut abgr = [255, 3, 2, 1];
+/// swizzle4_inplace(&mut abgr, [3, 2, 1, 0]);
+/// assert_eq!(abgr, [1, 2, 3, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4 or if any index in `order` is greater than 3.
+#[inline]
+pub fn swizzle4_inplace(src: &mut [u8], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    simd::swizzle4_inplace(src, order);
+    #[cfg(not(feature = "nightly"))]
+    sisd::swizzle4_inplace(src, order);
+}
+
+/// Reorder the channels of 4 channel data according to `order` and store the result to `dst`.
+///
+/// Channel `i` of every output pixel is taken from channel `order[i]` of the input pixel, so
+/// the same input channel may be used more than once.
+///
+/// ```rust
+/// use image_swizzle::swizzle4;
+/// let abgr = [255, 3, 2, 1];
+/// let mut bgra = [0; 4];
+/// swizzle4(&abgr, &mut bgra, [1, 2, 3, 0]);
+/// assert_eq!(bgra, [3, 2, 1, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn swizzle4(src: &[u8], dst: &mut [u8], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    simd::swizzle4(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    sisd::swizzle4(src, dst, order);
+}
+
+/// Reorder the channels of 4 channel data according to a compile time channel order while
+/// overwriting the old data in `src`.
+///
+/// Channel `i` of every output pixel is taken from the input channel given by the `i`th const
+/// parameter. Indices greater than 3 are rejected at compile time.
+///
+/// ```rust
+/// use image_swizzle::swizzle_inplace;
+/// let mut abgr = [255, 3, 2, 1];
+/// swizzle_inplace::<3, 2, 1, 0>(&mut abgr);
+/// assert_eq!(abgr, [1, 2, 3, 255]);
 /// ```
 ///
 /// Panics if `src.len` is not multiple of a 4.
 #[inline]
-pub fn argb_to_rgba_inplace(src: &mut [u8]) {
+pub fn swizzle_inplace<const A: usize, const B: usize, const C: usize, const D: usize>(
+    src: &mut [u8],
+) {
+    #[cfg(feature = "nightly")]
+    simd::swizzle_inplace::<A, B, C, D>(src);
+    #[cfg(not(feature = "nightly"))]
+    sisd::swizzle_inplace::<A, B, C, D>(src);
+}
+
+/// Reorder the channels of 4 channel data according to a compile time channel order and store
+/// the result to `dst`.
+///
+/// Channel `i` of every output pixel is taken from the input channel given by the `i`th const
+/// parameter. Indices greater than 3 are rejected at compile time.
+///
+/// ```rust
+/// use image_swizzle::swizzle;
+///
+/// fn abgr_to_bgra(src: &[u8], dst: &mut [u8]) {
+///     swizzle::<1, 2, 3, 0>(src, dst);
+/// }
+///
+/// let abgr = [255, 3, 2, 1];
+/// let mut bgra = [0; 4];
+/// abgr_to_bgra(&abgr, &mut bgra);
+/// assert_eq!(bgra, [3, 2, 1, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+#[inline]
+pub fn swizzle<const A: usize, const B: usize, const C: usize, const D: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+) {
     #[cfg(feature = "nightly")]
-    simd::argb_to_rgba_inplace(src);
+    simd::swizzle::<A, B, C, D>(src, dst);
     #[cfg(not(feature = "nightly"))]
-    sisd::argb_to_rgba_inplace(src);
+    sisd::swizzle::<A, B, C, D>(src, dst);
+}
+
+macro_rules! impl_try {
+    ($($try_name:ident => $name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Fallible version of [`", stringify!($name), "`].")]
+            ///
+            /// Returns an error if `src.len` is not multiple of a 4 or if `dst.len` is not equal
+            /// to `src.len`.
+            #[inline]
+            pub fn $try_name(src: &[u8], dst: &mut [u8]) -> Result<(), SwizzleError> {
+                error::check_lens(src.len(), dst.len(), 4)?;
+                $name(src, dst);
+                Ok(())
+            }
+        )*
+    };
+}
+
+macro_rules! impl_try_inplace {
+    ($($try_name:ident => $name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Fallible version of [`", stringify!($name), "`].")]
+            ///
+            /// Returns an error if `src.len` is not multiple of a 4.
+            #[inline]
+            pub fn $try_name(src: &mut [u8]) -> Result<(), SwizzleError> {
+                error::check_len(src.len(), 4)?;
+                $name(src);
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl_try! {
+    try_rgba_to_bgra => rgba_to_bgra,
+    try_rgba32_to_bgra => rgba32_to_bgra,
+    try_rgba32_to_rgba => rgba32_to_rgba,
+    try_bgra_to_rgba => bgra_to_rgba,
+    try_rgb0_to_rgbx => rgb0_to_rgbx,
+    try_bgr0_to_bgrx => bgr0_to_bgrx,
+    try_rgb0_to_bgrx => rgb0_to_bgrx,
+    try_bgr0_to_rgbx => bgr0_to_rgbx,
+}
+
+impl_try_inplace! {
+    try_rgba_to_bgra_inplace => rgba_to_bgra_inplace,
+    try_rgba32_to_bgra_inplace => rgba32_to_bgra_inplace,
+    try_rgba32_to_rgba_inplace => rgba32_to_rgba_inplace,
+    try_bgra_to_rgba_inplace => bgra_to_rgba_inplace,
+    try_rgb0_to_rgbx_inplace => rgb0_to_rgbx_inplace,
+    try_bgr0_to_bgrx_inplace => bgr0_to_bgrx_inplace,
+    try_rgb0_to_bgrx_inplace => rgb0_to_bgrx_inplace,
+    try_bgr0_to_rgbx_inplace => bgr0_to_rgbx_inplace,
+}
+
+macro_rules! impl_try_conversions {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?
+        => $try_name:ident, $try_inplace:ident, $strided:ident, $strided_inplace:ident;)*) => {
+        impl_try! { $($try_name => $name),* }
+        impl_try_inplace! { $($try_inplace => $inplace),* }
+    };
+}
+
+common::for_each_conversion!(@variants impl_try_conversions);
+
+/// Fallible version of [`swizzle4_inplace`].
+///
+/// Returns an error if `src.len` is not multiple of a 4 or if any index in `order` is greater
+/// than 3.
+#[inline]
+pub fn try_swizzle4_inplace(src: &mut [u8], order: [u8; 4]) -> Result<(), SwizzleError> {
+    error::check_len(src.len(), 4)?;
+    error::check_order(order)?;
+    swizzle4_inplace(src, order);
+    Ok(())
+}
+
+/// Fallible version of [`swizzle4`].
+///
+/// Returns an error if `src.len` is not multiple of a 4, if `dst.len` is not equal to
+/// `src.len` or if any index in `order` is greater than 3.
+#[inline]
+pub fn try_swizzle4(src: &[u8], dst: &mut [u8], order: [u8; 4]) -> Result<(), SwizzleError> {
+    error::check_lens(src.len(), dst.len(), 4)?;
+    error::check_order(order)?;
+    swizzle4(src, dst, order);
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_try_rgba_to_bgra() {
+        let mut bgra = [0; 8];
+        assert_eq!(try_rgba_to_bgra(&[1, 2, 3, 4, 5, 6, 7, 8], &mut bgra), Ok(()));
+        assert_eq!(bgra, [3, 2, 1, 4, 7, 6, 5, 8]);
+        assert_eq!(
+            try_rgba_to_bgra(&[0; 4], &mut bgra),
+            Err(SwizzleError::LengthMismatch { src: 4, dst: 8 })
+        );
+        assert_eq!(
+            try_rgba_to_bgra(&[0; 6], &mut bgra[..6]),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 6,
+                pixel_size: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_inplace() {
+        let mut rgb0 = [1, 2, 3, 0];
+        assert_eq!(try_rgb0_to_rgbx_inplace(&mut rgb0), Ok(()));
+        assert_eq!(rgb0, [1, 2, 3, 255]);
+        let mut abgr = [4, 3, 2, 1];
+        assert_eq!(try_abgr_to_bgr0_inplace(&mut abgr), Ok(()));
+        assert_eq!(abgr, [3, 2, 1, 255]);
+        assert_eq!(
+            try_argb_to_rgba_inplace(&mut [0; 3]),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 3,
+                pixel_size: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_swizzle4() {
+        let mut dst = [0; 4];
+        assert_eq!(
+            try_swizzle4(&[1, 2, 3, 4], &mut dst, [0, 1, 2, 4]),
+            Err(SwizzleError::InvalidOrder { order: [0, 1, 2, 4] })
+        );
+        assert_eq!(try_swizzle4(&[1, 2, 3, 4], &mut dst, [3, 3, 0, 1]), Ok(()));
+        assert_eq!(dst, [4, 4, 1, 2]);
+        let mut src = [1, 2, 3, 4];
+        assert_eq!(
+            try_swizzle4_inplace(&mut src, [9, 0, 0, 0]),
+            Err(SwizzleError::InvalidOrder { order: [9, 0, 0, 0] })
+        );
+        assert_eq!(src, [1, 2, 3, 4]);
+    }
+
     common::impl_tests!{}
 }
@@ -0,0 +1,463 @@
+//! Pixels described by channel bitmasks, like X11 visuals and Linux framebuffer bitfields.
+
+use crate::error::check_len;
+use crate::format::Channel;
+use crate::{PixelFormat, SwizzleError};
+
+/// Order of the bytes of a pixel in memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ByteOrder {
+    /// Least significant byte first, X11's `LSBFirst`.
+    Little,
+    /// Most significant byte first, X11's `MSBFirst`.
+    Big,
+}
+
+impl ByteOrder {
+    /// Byte order of the target.
+    pub const NATIVE: ByteOrder = if cfg!(target_endian = "little") {
+        ByteOrder::Little
+    } else {
+        ByteOrder::Big
+    };
+}
+
+/// Layout of a pixel given as one bitmask per channel, e.g. the `red_mask`, `green_mask` and
+/// `blue_mask` of an X11 `XImage`.
+///
+/// A pixel is `bits_per_pixel / 8` bytes that are read as an integer in `byte_order`, a channel
+/// is the bits selected by its mask. Masks must be contiguous and must not overlap, an `alpha`
+/// mask of 0 means the pixels have no alpha channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChannelMasks {
+    pub bits_per_pixel: u32,
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+    pub alpha: u32,
+    pub byte_order: ByteOrder,
+}
+
+impl ChannelMasks {
+    pub fn new(
+        bits_per_pixel: u32,
+        red: u32,
+        green: u32,
+        blue: u32,
+        alpha: u32,
+        byte_order: ByteOrder,
+    ) -> Self {
+        Self {
+            bits_per_pixel,
+            red,
+            green,
+            blue,
+            alpha,
+            byte_order,
+        }
+    }
+
+    /// Build masks from `(offset, length)` bitfields in native byte order, like the `red`,
+    /// `green`, `blue` and `transp` fields of Linux's `fb_var_screeninfo`.
+    ///
+    /// ```rust
+    /// use image_swizzle::{ByteOrder, ChannelMasks};
+    /// let rgb565 = ChannelMasks::from_bitfields(16, (11, 5), (5, 6), (0, 5), (0, 0));
+    /// assert_eq!(rgb565, ChannelMasks::new(16, 0xf800, 0x07e0, 0x001f, 0, ByteOrder::NATIVE));
+    /// ```
+    pub fn from_bitfields(
+        bits_per_pixel: u32,
+        red: (u32, u32),
+        green: (u32, u32),
+        blue: (u32, u32),
+        alpha: (u32, u32),
+    ) -> Self {
+        let mask = |(offset, length): (u32, u32)| {
+            let mask = ((1u64 << length.min(32)) - 1) << offset.min(32);
+            u32::try_from(mask).unwrap_or(u32::MAX)
+        };
+        Self::new(
+            bits_per_pixel,
+            mask(red),
+            mask(green),
+            mask(blue),
+            mask(alpha),
+            ByteOrder::NATIVE,
+        )
+    }
+
+    /// Returns the number of bytes of a pixel.
+    pub fn bytes_per_pixel(&self) -> usize {
+        self.bits_per_pixel as usize / 8
+    }
+
+    fn masks(&self) -> [u32; 4] {
+        [self.red, self.green, self.blue, self.alpha]
+    }
+
+    /// Returns an error if the layout is not supported.
+    pub(crate) fn validate(&self) -> Result<(), SwizzleError> {
+        let contiguous = |mask: u32| {
+            let bits = mask.checked_shr(mask.trailing_zeros()).unwrap_or(0);
+            bits & bits.wrapping_add(1) == 0
+        };
+        let fits = |mask: u32| self.bits_per_pixel == 32 || mask >> self.bits_per_pixel == 0;
+        let masks = self.masks();
+        let bits = masks.iter().map(|mask| mask.count_ones()).sum::<u32>();
+        let union = masks.iter().fold(0, |union, mask| union | mask);
+
+        if !matches!(self.bits_per_pixel, 8 | 16 | 24 | 32)
+            || masks[..3].contains(&0)
+            || !masks.into_iter().all(|mask| contiguous(mask) && fits(mask))
+            || bits != union.count_ones()
+        {
+            return Err(SwizzleError::InvalidMasks { masks: *self });
+        }
+        Ok(())
+    }
+
+    /// Returns the [`PixelFormat`] with the same layout, if there is one.
+    ///
+    /// ```rust
+    /// use image_swizzle::{ByteOrder, ChannelMasks, PixelFormat};
+    /// let visual = ChannelMasks::new(32, 0xff0000, 0xff00, 0xff, 0, ByteOrder::Little);
+    /// assert_eq!(visual.pixel_format(), Some(PixelFormat::Bgrx));
+    /// ```
+    pub fn pixel_format(&self) -> Option<PixelFormat> {
+        if self.bits_per_pixel != 32 || self.validate().is_err() {
+            return None;
+        }
+
+        let mut channels = [Channel::X; 4];
+        let named = [Channel::R, Channel::G, Channel::B, Channel::A];
+        for (mask, channel) in self.masks().into_iter().zip(named) {
+            if mask == 0 {
+                continue;
+            }
+            let shift = mask.trailing_zeros();
+            if mask != 0xff << shift || shift % 8 != 0 {
+                return None;
+            }
+            let byte = match self.byte_order {
+                ByteOrder::Little => shift / 8,
+                ByteOrder::Big => 3 - shift / 8,
+            };
+            channels[byte as usize] = channel;
+        }
+        // Unused bytes are `Channel::X`, so the `*0` formats never match.
+        PixelFormat::ALL
+            .into_iter()
+            .find(|format| format.channels() == channels)
+    }
+
+    /// Reads the pixel stored in `px`.
+    fn read(&self, px: &[u8]) -> u32 {
+        match self.byte_order {
+            ByteOrder::Little => px.iter().rev().fold(0, |value, &b| value << 8 | b as u32),
+            ByteOrder::Big => px.iter().fold(0, |value, &b| value << 8 | b as u32),
+        }
+    }
+
+    /// Stores the pixel `value` to `px`.
+    fn write(&self, px: &mut [u8], value: u32) {
+        let len = px.len();
+        for (i, b) in px.iter_mut().enumerate() {
+            let byte = match self.byte_order {
+                ByteOrder::Little => i,
+                ByteOrder::Big => len - 1 - i,
+            };
+            *b = (value >> (byte * 8)) as u8;
+        }
+    }
+}
+
+/// Scales the channel selected by `mask` to 8 bits.
+fn extract(value: u32, mask: u32) -> u8 {
+    let shift = mask.trailing_zeros();
+    let max = (mask >> shift) as u64;
+    let channel = ((value & mask) >> shift) as u64;
+    ((channel * 255 + max / 2) / max) as u8
+}
+
+/// Scales the 8 bit `channel` to the bits selected by `mask`.
+fn insert(channel: u8, mask: u32) -> u32 {
+    let shift = mask.trailing_zeros();
+    let max = (mask >> shift) as u64;
+    (((channel as u64 * max + 127) / 255) as u32) << shift
+}
+
+/// Checks that `src` and `dst` are whole pixels of `src_size` and `dst_size` bytes and that they
+/// hold the same number of pixels.
+fn check_pixels(
+    src: usize,
+    src_size: usize,
+    dst: usize,
+    dst_size: usize,
+) -> Result<(), SwizzleError> {
+    check_len(src, src_size)?;
+    check_len(dst, dst_size)?;
+    if src / src_size != dst / dst_size {
+        return Err(SwizzleError::LengthMismatch { src, dst });
+    }
+    Ok(())
+}
+
+/// Convert `src` with the channel layout `masks` to `dst_fmt` and store the result to `dst`.
+///
+/// Layouts that match a [`PixelFormat`] are routed to the swizzle kernels, others are converted
+/// one pixel at a time with every channel scaled to 8 bits.
+///
+/// ```rust
+/// use image_swizzle::{convert_from_masks, ChannelMasks, PixelFormat};
+/// let rgb565 = ChannelMasks::from_bitfields(16, (11, 5), (5, 6), (0, 5), (0, 0));
+/// let src = 0xf81fu16.to_ne_bytes();
+/// let mut rgba = [0; 4];
+/// convert_from_masks(&src, &rgb565, &mut rgba, PixelFormat::Rgba).unwrap();
+/// assert_eq!(rgba, [255, 0, 255, 255]);
+/// ```
+///
+/// Returns an error if the layout is not supported, if `src.len` is not a multiple of the pixel
+/// size or if `dst` does not hold exactly as many pixels as `src`.
+pub fn convert_from_masks(
+    src: &[u8],
+    masks: &ChannelMasks,
+    dst: &mut [u8],
+    dst_fmt: PixelFormat,
+) -> Result<(), SwizzleError> {
+    masks.validate()?;
+    let pixel_size = masks.bytes_per_pixel();
+    check_pixels(src.len(), pixel_size, dst.len(), 4)?;
+
+    if let Some(src_fmt) = masks.pixel_format() {
+        return crate::convert(src, src_fmt, dst, dst_fmt);
+    }
+
+    let channels = dst_fmt.channels();
+    for (px, out) in src.chunks_exact(pixel_size).zip(dst.chunks_exact_mut(4)) {
+        let value = masks.read(px);
+        for (out, channel) in out.iter_mut().zip(channels) {
+            *out = match channel {
+                Channel::R => extract(value, masks.red),
+                Channel::G => extract(value, masks.green),
+                Channel::B => extract(value, masks.blue),
+                Channel::A if masks.alpha != 0 => extract(value, masks.alpha),
+                Channel::A | Channel::X | Channel::Zero => 255,
+            };
+        }
+    }
+    Ok(())
+}
+
+/// Convert `src` in the `src_fmt` format to the channel layout `masks` and store the result to
+/// `dst`.
+///
+/// Layouts that match a [`PixelFormat`] are routed to the swizzle kernels, others are converted
+/// one pixel at a time with every channel scaled from 8 bits and unused bits set to 0.
+///
+/// ```rust
+/// use image_swizzle::{convert_to_masks, ChannelMasks, PixelFormat};
+/// let rgb565 = ChannelMasks::from_bitfields(16, (11, 5), (5, 6), (0, 5), (0, 0));
+/// let mut dst = [0; 2];
+/// convert_to_masks(&[255, 0, 255, 255], PixelFormat::Rgba, &mut dst, &rgb565).unwrap();
+/// assert_eq!(u16::from_ne_bytes(dst), 0xf81f);
+/// ```
+///
+/// Returns an error if the layout is not supported, if `src.len` is not a multiple of 4 or if
+/// `dst` does not hold exactly as many pixels as `src`.
+pub fn convert_to_masks(
+    src: &[u8],
+    src_fmt: PixelFormat,
+    dst: &mut [u8],
+    masks: &ChannelMasks,
+) -> Result<(), SwizzleError> {
+    masks.validate()?;
+    let pixel_size = masks.bytes_per_pixel();
+    check_pixels(src.len(), 4, dst.len(), pixel_size)?;
+
+    if let Some(dst_fmt) = masks.pixel_format() {
+        return crate::convert(src, src_fmt, dst, dst_fmt);
+    }
+
+    let channels = src_fmt.channels();
+    let lane = |channel| channels.iter().position(|&c| c == channel);
+    let (r, g, b) = (
+        lane(Channel::R).unwrap(),
+        lane(Channel::G).unwrap(),
+        lane(Channel::B).unwrap(),
+    );
+    let a = lane(Channel::A);
+    for (px, out) in src.chunks_exact(4).zip(dst.chunks_exact_mut(pixel_size)) {
+        let mut value = insert(px[r], masks.red) | insert(px[g], masks.green);
+        value |= insert(px[b], masks.blue);
+        if masks.alpha != 0 {
+            value |= insert(a.map_or(255, |a| px[a]), masks.alpha);
+        }
+        masks.write(out, value);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pixel_format() {
+        #[rustfmt::skip]
+        let cases = [
+            (0x00ff0000, 0x0000ff00, 0x000000ff, 0x00000000, ByteOrder::Little, Some(PixelFormat::Bgrx)),
+            (0x00ff0000, 0x0000ff00, 0x000000ff, 0xff000000, ByteOrder::Little, Some(PixelFormat::Bgra)),
+            (0x00ff0000, 0x0000ff00, 0x000000ff, 0x00000000, ByteOrder::Big, Some(PixelFormat::Xrgb)),
+            (0x000000ff, 0x0000ff00, 0x00ff0000, 0xff000000, ByteOrder::Little, Some(PixelFormat::Rgba)),
+            (0xff000000, 0x00ff0000, 0x0000ff00, 0x000000ff, ByteOrder::Big, Some(PixelFormat::Rgba)),
+            (0x000000ff, 0x0000ff00, 0x00ff0000, 0x00000000, ByteOrder::Big, Some(PixelFormat::Xbgr)),
+            (0x3ff00000, 0x000ffc00, 0x000003ff, 0xc0000000, ByteOrder::Little, None),
+            (0x00ff0000, 0x0000ff00, 0x000000ff, 0x0f000000, ByteOrder::Little, None),
+        ];
+        for (red, green, blue, alpha, byte_order, format) in cases {
+            let masks = ChannelMasks::new(32, red, green, blue, alpha, byte_order);
+            assert_eq!(masks.pixel_format(), format, "{masks:?}");
+        }
+        let rgb888 = ChannelMasks::new(24, 0xff0000, 0xff00, 0xff, 0, ByteOrder::Little);
+        assert_eq!(rgb888.pixel_format(), None);
+    }
+
+    #[test]
+    fn test_fast_path_matches_generic() {
+        // The same layout as xRGB but with a 7 bit alpha, so the generic path is taken.
+        let generic = ChannelMasks::new(32, 0xff0000, 0xff00, 0xff, 0x7f000000, ByteOrder::Big);
+        let fast = ChannelMasks::new(32, 0xff0000, 0xff00, 0xff, 0, ByteOrder::Big);
+        let src = [0x80, 1, 2, 3, 0x7f, 4, 5, 6].repeat(19);
+        let mut expected = vec![0; src.len()];
+        let mut dst = vec![0; src.len()];
+        convert_from_masks(&src, &generic, &mut expected, PixelFormat::Bgrx).unwrap();
+        convert_from_masks(&src, &fast, &mut dst, PixelFormat::Bgrx).unwrap();
+        assert_eq!(dst, expected);
+        assert_eq!(&dst[..8], [3, 2, 1, 255, 6, 5, 4, 255]);
+    }
+
+    #[test]
+    fn test_convert_rgb565() {
+        let rgb565 = ChannelMasks::new(16, 0xf800, 0x07e0, 0x001f, 0, ByteOrder::Big);
+        let src = [0xf8, 0x00, 0x07, 0xe0, 0x00, 0x1f, 0x84, 0x10];
+        let mut dst = [0; 16];
+        convert_from_masks(&src, &rgb565, &mut dst, PixelFormat::Bgra).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(dst, [
+            0, 0, 255, 255,
+            0, 255, 0, 255,
+            255, 0, 0, 255,
+            132, 130, 132, 255,
+        ]);
+    }
+
+    #[test]
+    fn test_convert_argb1555_and_rgb332() {
+        let argb1555 = ChannelMasks::from_bitfields(16, (10, 5), (5, 5), (0, 5), (15, 1));
+        let src = 0x7c1fu16.to_ne_bytes();
+        let mut dst = [0; 4];
+        convert_from_masks(&src, &argb1555, &mut dst, PixelFormat::Rgba).unwrap();
+        assert_eq!(dst, [255, 0, 255, 0]);
+
+        let rgb332 = ChannelMasks::new(8, 0xe0, 0x1c, 0x03, 0, ByteOrder::Little);
+        convert_from_masks(&[0b1010_1001], &rgb332, &mut dst, PixelFormat::Rgba).unwrap();
+        assert_eq!(dst, [182, 73, 85, 255]);
+    }
+
+    #[test]
+    fn test_convert_24bpp() {
+        let little = ChannelMasks::new(24, 0xff0000, 0xff00, 0xff, 0, ByteOrder::Little);
+        let big = ChannelMasks {
+            byte_order: ByteOrder::Big,
+            ..little
+        };
+        let src = [1, 2, 3, 4, 5, 6];
+        let mut dst = [0; 8];
+        convert_from_masks(&src, &little, &mut dst, PixelFormat::Rgbx).unwrap();
+        assert_eq!(dst, [3, 2, 1, 255, 6, 5, 4, 255]);
+        convert_from_masks(&src, &big, &mut dst, PixelFormat::Rgbx).unwrap();
+        assert_eq!(dst, [1, 2, 3, 255, 4, 5, 6, 255]);
+    }
+
+    #[test]
+    fn test_convert_2101010() {
+        let masks = ChannelMasks::from_bitfields(32, (20, 10), (10, 10), (0, 10), (30, 2));
+        let src = (0b11 << 30 | 0x3ff << 20 | 0x200u32 << 10).to_ne_bytes();
+        let mut dst = [0; 4];
+        convert_from_masks(&src, &masks, &mut dst, PixelFormat::Argb).unwrap();
+        assert_eq!(dst, [255, 255, 128, 0]);
+    }
+
+    #[test]
+    fn test_convert_to_masks() {
+        let rgb565 = ChannelMasks::new(16, 0xf800, 0x07e0, 0x001f, 0, ByteOrder::Big);
+        let src = [132, 130, 132, 0, 0, 0, 255, 0];
+        let mut dst = [0; 4];
+        convert_to_masks(&src, PixelFormat::Bgr0, &mut dst, &rgb565).unwrap();
+        assert_eq!(dst, [0x84, 0x10, 0xf8, 0x00]);
+
+        let argb1555 = ChannelMasks::new(16, 0x7c00, 0x03e0, 0x001f, 0x8000, ByteOrder::Little);
+        let mut dst = [0; 2];
+        convert_to_masks(&[0, 0, 255, 255], PixelFormat::Rgbx, &mut dst, &argb1555).unwrap();
+        assert_eq!(dst, [0x1f, 0x80]);
+        convert_to_masks(&[0, 0, 255, 0], PixelFormat::Rgba, &mut dst, &argb1555).unwrap();
+        assert_eq!(dst, [0x1f, 0x00]);
+    }
+
+    #[test]
+    fn test_masks_roundtrip() {
+        let layouts = [
+            ChannelMasks::new(8, 0x07, 0x38, 0xc0, 0, ByteOrder::Little),
+            ChannelMasks::new(16, 0xf800, 0x07e0, 0x001f, 0, ByteOrder::Little),
+            ChannelMasks::new(16, 0x0f00, 0x00f0, 0x000f, 0xf000, ByteOrder::Big),
+            ChannelMasks::new(24, 0xff, 0xff00, 0xff0000, 0, ByteOrder::Big),
+            ChannelMasks::new(32, 0x3ff, 0xffc00, 0x3ff00000, 0, ByteOrder::Little),
+            ChannelMasks::new(32, 0xff00, 0xff0000, 0xff000000, 0xff, ByteOrder::Little),
+        ];
+        let src = (0..=255)
+            .flat_map(|v| [v, 255 - v, v / 2, 255])
+            .collect::<Vec<u8>>();
+        for masks in layouts {
+            let mut packed = vec![0; src.len() / 4 * masks.bytes_per_pixel()];
+            let mut unpacked = vec![0; src.len()];
+            convert_to_masks(&src, PixelFormat::Rgba, &mut packed, &masks).unwrap();
+            convert_from_masks(&packed, &masks, &mut unpacked, PixelFormat::Rgba).unwrap();
+            let mut repacked = vec![0; packed.len()];
+            convert_to_masks(&unpacked, PixelFormat::Rgba, &mut repacked, &masks).unwrap();
+            assert_eq!(packed, repacked, "{masks:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_masks() {
+        let invalid = [
+            ChannelMasks::new(12, 0xf00, 0xf0, 0xf, 0, ByteOrder::Little),
+            ChannelMasks::new(16, 0xf800, 0x07e0, 0, 0, ByteOrder::Little),
+            ChannelMasks::new(16, 0xf800, 0x0fe0, 0x001f, 0, ByteOrder::Little),
+            ChannelMasks::new(16, 0xe800, 0x07e0, 0x001f, 0, ByteOrder::Little),
+            ChannelMasks::new(16, 0x1f0000, 0x07e0, 0x001f, 0, ByteOrder::Little),
+        ];
+        for masks in invalid {
+            assert_eq!(
+                convert_from_masks(&[0; 4], &masks, &mut [0; 8], PixelFormat::Rgba),
+                Err(SwizzleError::InvalidMasks { masks })
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        let rgb565 = ChannelMasks::from_bitfields(16, (11, 5), (5, 6), (0, 5), (0, 0));
+        assert_eq!(
+            convert_from_masks(&[0; 3], &rgb565, &mut [0; 4], PixelFormat::Rgba),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 3,
+                pixel_size: 2
+            })
+        );
+        assert_eq!(
+            convert_from_masks(&[0; 4], &rgb565, &mut [0; 4], PixelFormat::Rgba),
+            Err(SwizzleError::LengthMismatch { src: 4, dst: 4 })
+        );
+    }
+}
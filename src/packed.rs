@@ -0,0 +1,246 @@
+//! Conversions between byte ordered pixels and packed `u32` pixels.
+//!
+//! A packed pixel stores its channels in a native endian `u32`, so the order of the bytes in
+//! memory depends on the endianness of the target. Packed formats are named by their channels
+//! from the most to the least significant byte, e.g. Cairo's `CAIRO_FORMAT_ARGB32` and pixman's
+//! `a8r8g8b8` are both `0xAARRGGBB` which is [`PixelFormat::Argb`] here.
+
+use std::slice;
+
+use crate::error::check_lens;
+use crate::{PixelFormat, SwizzleError};
+
+fn as_bytes(src: &[u32]) -> &[u8] {
+    // SAFETY: u8 has no alignment requirements and every bit pattern is valid for both types.
+    unsafe { slice::from_raw_parts(src.as_ptr().cast(), std::mem::size_of_val(src)) }
+}
+
+fn as_bytes_mut(src: &mut [u32]) -> &mut [u8] {
+    // SAFETY: u8 has no alignment requirements and every bit pattern is valid for both types.
+    unsafe { slice::from_raw_parts_mut(src.as_mut_ptr().cast(), std::mem::size_of_val(src)) }
+}
+
+/// Byte order of a packed pixel of `format` in memory.
+const fn memory_format(format: PixelFormat) -> PixelFormat {
+    if cfg!(target_endian = "little") {
+        format.reversed()
+    } else {
+        format
+    }
+}
+
+/// Convert packed pixels in the `src_fmt` format to byte ordered `dst_fmt` pixels and store the
+/// result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::{packed, PixelFormat};
+/// let argb32 = [0xff010203u32];
+/// let mut rgba = [0; 4];
+/// packed::unpack(&argb32, PixelFormat::Argb, &mut rgba, PixelFormat::Rgba).unwrap();
+/// assert_eq!(rgba, [1, 2, 3, 255]);
+/// ```
+///
+/// Returns an error if `dst.len` is not equal to `src.len * 4`.
+pub fn unpack(
+    src: &[u32],
+    src_fmt: PixelFormat,
+    dst: &mut [u8],
+    dst_fmt: PixelFormat,
+) -> Result<(), SwizzleError> {
+    crate::convert(as_bytes(src), memory_format(src_fmt), dst, dst_fmt)
+}
+
+/// Convert byte ordered pixels in the `src_fmt` format to packed `dst_fmt` pixels and store the
+/// result to `dst`.
+///
+/// ```rust
+/// use image_swizzle::{packed, PixelFormat};
+/// let bgra = [3, 2, 1, 255];
+/// let mut argb32 = [0u32];
+/// packed::pack(&bgra, PixelFormat::Bgra, &mut argb32, PixelFormat::Argb).unwrap();
+/// assert_eq!(argb32, [0xff010203]);
+/// ```
+///
+/// Returns an error if `src.len` is not equal to `dst.len * 4`.
+pub fn pack(
+    src: &[u8],
+    src_fmt: PixelFormat,
+    dst: &mut [u32],
+    dst_fmt: PixelFormat,
+) -> Result<(), SwizzleError> {
+    check_lens(src.len(), dst.len() * 4, 4)?;
+    crate::convert(src, src_fmt, as_bytes_mut(dst), memory_format(dst_fmt))
+}
+
+/// Convert packed pixels from the `src_fmt` format to the packed `dst_fmt` format while
+/// overwriting the old data in `src`.
+///
+/// ```rust
+/// use image_swizzle::{packed, PixelFormat};
+/// let mut pixels = [0x010203ffu32];
+/// packed::convert_inplace(&mut pixels, PixelFormat::Rgba, PixelFormat::Argb).unwrap();
+/// assert_eq!(pixels, [0xff010203]);
+/// ```
+pub fn convert_inplace(
+    src: &mut [u32],
+    src_fmt: PixelFormat,
+    dst_fmt: PixelFormat,
+) -> Result<(), SwizzleError> {
+    crate::convert_inplace(
+        as_bytes_mut(src),
+        memory_format(src_fmt),
+        memory_format(dst_fmt),
+    )
+}
+
+/// Convert native endian `0xAARRGGBB` pixels, like Cairo's `ARGB32`, to RGBA and store the
+/// result to `dst`.
+///
+/// Panics if `dst.len` is not equal to `src.len * 4`.
+#[inline]
+pub fn argb32_to_rgba(src: &[u32], dst: &mut [u8]) {
+    #[cfg(target_endian = "little")]
+    crate::bgra_to_rgba(as_bytes(src), dst);
+    #[cfg(target_endian = "big")]
+    crate::argb_to_rgba(as_bytes(src), dst);
+}
+
+/// Convert native endian `0xAARRGGBB` pixels, like Cairo's `ARGB32`, to BGRA and store the
+/// result to `dst`.
+///
+/// Panics if `dst.len` is not equal to `src.len * 4`.
+#[inline]
+pub fn argb32_to_bgra(src: &[u32], dst: &mut [u8]) {
+    #[cfg(target_endian = "little")]
+    {
+        assert!(src.len() * 4 == dst.len());
+        dst.copy_from_slice(as_bytes(src));
+    }
+    #[cfg(target_endian = "big")]
+    crate::argb_to_bgra(as_bytes(src), dst);
+}
+
+/// Convert RGBA data to native endian `0xAARRGGBB` pixels and store the result to `dst`.
+///
+/// Panics if `src.len` is not equal to `dst.len * 4`.
+#[inline]
+pub fn rgba_to_argb32(src: &[u8], dst: &mut [u32]) {
+    #[cfg(target_endian = "little")]
+    crate::rgba_to_bgra(src, as_bytes_mut(dst));
+    #[cfg(target_endian = "big")]
+    crate::rgba_to_argb(src, as_bytes_mut(dst));
+}
+
+/// Convert BGRA data to native endian `0xAARRGGBB` pixels and store the result to `dst`.
+///
+/// Panics if `src.len` is not equal to `dst.len * 4`.
+#[inline]
+pub fn bgra_to_argb32(src: &[u8], dst: &mut [u32]) {
+    #[cfg(target_endian = "little")]
+    {
+        assert!(src.len() == dst.len() * 4);
+        as_bytes_mut(dst).copy_from_slice(src);
+    }
+    #[cfg(target_endian = "big")]
+    crate::bgra_to_argb(src, as_bytes_mut(dst));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_pixel;
+    use crate::format::Channel;
+
+    /// Formats whose every output byte is specified, so the results can be compared exactly.
+    fn formats() -> impl Iterator<Item = PixelFormat> {
+        PixelFormat::ALL
+            .into_iter()
+            .filter(|format| !format.channels().contains(&Channel::Zero))
+    }
+
+    /// Packs a pixel with shifts only, so the result does not depend on the endianness of the
+    /// target.
+    fn pack_scalar(px: [u8; 4]) -> u32 {
+        (px[0] as u32) << 24 | (px[1] as u32) << 16 | (px[2] as u32) << 8 | px[3] as u32
+    }
+
+    fn unpack_scalar(px: u32) -> [u8; 4] {
+        [(px >> 24) as u8, (px >> 16) as u8, (px >> 8) as u8, px as u8]
+    }
+
+    /// Expected output of converting `test_pixel(src_fmt)` to `dst_fmt`.
+    fn expected(src_fmt: PixelFormat, dst_fmt: PixelFormat) -> [u8; 4] {
+        dst_fmt.channels().map(|channel| match channel {
+            Channel::R => 1,
+            Channel::G => 2,
+            Channel::B => 3,
+            Channel::A if src_fmt.has_alpha() => 4,
+            Channel::A | Channel::X | Channel::Zero => 255,
+        })
+    }
+
+    #[test]
+    fn test_unpack() {
+        for src_fmt in formats() {
+            for dst_fmt in formats() {
+                let src = vec![pack_scalar(test_pixel(src_fmt)); 37];
+                let mut dst = vec![0; src.len() * 4];
+                unpack(&src, src_fmt, &mut dst, dst_fmt).unwrap();
+                assert_eq!(dst, expected(src_fmt, dst_fmt).repeat(37));
+            }
+        }
+    }
+
+    #[test]
+    fn test_pack() {
+        for src_fmt in formats() {
+            for dst_fmt in formats() {
+                let src = test_pixel(src_fmt).repeat(37);
+                let mut dst = vec![0; 37];
+                pack(&src, src_fmt, &mut dst, dst_fmt).unwrap();
+                for px in dst {
+                    assert_eq!(unpack_scalar(px), expected(src_fmt, dst_fmt));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_inplace() {
+        for src_fmt in formats() {
+            for dst_fmt in formats() {
+                let mut pixels = vec![pack_scalar(test_pixel(src_fmt)); 37];
+                convert_inplace(&mut pixels, src_fmt, dst_fmt).unwrap();
+                for px in pixels {
+                    assert_eq!(unpack_scalar(px), expected(src_fmt, dst_fmt));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_argb32() {
+        let argb32 = vec![0x04010203; 37];
+        let mut dst = vec![0; argb32.len() * 4];
+        argb32_to_rgba(&argb32, &mut dst);
+        assert_eq!(dst, [1, 2, 3, 4].repeat(37));
+        argb32_to_bgra(&argb32, &mut dst);
+        assert_eq!(dst, [3, 2, 1, 4].repeat(37));
+
+        let mut packed = vec![0; 37];
+        rgba_to_argb32(&[1, 2, 3, 4].repeat(37), &mut packed);
+        assert_eq!(packed, argb32);
+        packed.fill(0);
+        bgra_to_argb32(&[3, 2, 1, 4].repeat(37), &mut packed);
+        assert_eq!(packed, argb32);
+    }
+
+    #[test]
+    fn test_pack_length_mismatch() {
+        let mut dst = [0; 2];
+        assert_eq!(
+            pack(&[0; 4], PixelFormat::Rgba, &mut dst, PixelFormat::Argb),
+            Err(SwizzleError::LengthMismatch { src: 4, dst: 8 })
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Conversions between 3 and 4 byte pixels inside a single `Vec`.
+//!
+//! A 4 byte pixel only overwrites 3 byte pixels that have already been converted as long as
+//! every block of pixels is converted after the blocks it overlaps, so the buffer is converted
+//! in shrinking blocks from the back when it grows and in growing blocks from the front when it
+//! shrinks. The blocks are handed to the out-of-place kernels.
+
+use crate::error::check_len;
+use crate::{PixelFormat, PixelFormat24, SwizzleError};
+
+type Expansion = fn(&[u8], &mut [u8], u8);
+type Packing = fn(&[u8], &mut [u8]);
+
+macro_rules! impl_expansion_kernel {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $alpha:ident)?;)*) => {
+        /// Returns the kernel that expands `src` to `dst`, the padding of `*0` formats is
+        /// filled like the matching `*x` format.
+        fn expansion(src: PixelFormat24, dst: PixelFormat) -> Expansion {
+            match (src, dst) {
+                $((PixelFormat24::$src, PixelFormat::$dst) => {
+                    impl_expansion_kernel!(@kernel $name $(, $alpha)?)
+                })*
+                (PixelFormat24::Rgb24, PixelFormat::Rgb0) => |src, dst, _| crate::rgb24_to_rgbx(src, dst),
+                (PixelFormat24::Rgb24, PixelFormat::Bgr0) => |src, dst, _| crate::rgb24_to_bgrx(src, dst),
+                (PixelFormat24::Bgr24, PixelFormat::Rgb0) => |src, dst, _| crate::bgr24_to_rgbx(src, dst),
+                (PixelFormat24::Bgr24, PixelFormat::Bgr0) => |src, dst, _| crate::bgr24_to_bgrx(src, dst),
+            }
+        }
+    };
+    (@kernel $name:ident, alpha) => {
+        crate::$name
+    };
+    (@kernel $name:ident) => {
+        |src, dst, _| crate::$name(src, dst)
+    };
+}
+
+macro_rules! impl_packing_kernel {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+        /// Returns the kernel that packs `src` to `dst`.
+        fn packing(src: PixelFormat, dst: PixelFormat24) -> Packing {
+            match (src, dst) {
+                $((PixelFormat::$src, PixelFormat24::$dst) => crate::$name,)*
+            }
+        }
+    };
+}
+
+crate::common::for_each_expansion!(impl_expansion_kernel);
+crate::common::for_each_packing!(impl_packing_kernel);
+
+/// Convert the 3 byte `src_fmt` pixels in `buf` to `dst_fmt` in place, growing `buf` by a third.
+///
+/// The alpha channel of formats with alpha is set to `alpha`. `buf` only reallocates if it
+/// does not have the capacity for the 4 byte pixels, so reserve it up front to convert without
+/// a second buffer.
+///
+/// ```rust
+/// use image_swizzle::{expand_vec, PixelFormat, PixelFormat24};
+/// let mut buf = Vec::with_capacity(8);
+/// buf.extend_from_slice(&[1, 2, 3, 4, 5, 6]);
+/// expand_vec(&mut buf, PixelFormat24::Rgb24, PixelFormat::Bgra, 255).unwrap();
+/// assert_eq!(buf, [3, 2, 1, 255, 6, 5, 4, 255]);
+/// ```
+///
+/// Returns an error if `buf.len` is not multiple of a 3.
+pub fn expand_vec(
+    buf: &mut Vec<u8>,
+    src_fmt: PixelFormat24,
+    dst_fmt: PixelFormat,
+    alpha: u8,
+) -> Result<(), SwizzleError> {
+    check_len(buf.len(), 3)?;
+    let kernel = expansion(src_fmt, dst_fmt);
+    let pixels = buf.len() / 3;
+    buf.resize(pixels * 4, 0);
+
+    let mut end = pixels;
+    while end > 0 {
+        // The pixels `start..end` are written after the 3 byte pixels before `start`.
+        let start = (3 * end).div_ceil(4);
+        if start == end {
+            let start = end - 1;
+            let px: [u8; 3] = buf[start * 3..end * 3].try_into().unwrap();
+            kernel(&px, &mut buf[start * 4..end * 4], alpha);
+            end = start;
+            continue;
+        }
+        let (head, tail) = buf.split_at_mut(start * 4);
+        kernel(
+            &head[start * 3..end * 3],
+            &mut tail[..(end - start) * 4],
+            alpha,
+        );
+        end = start;
+    }
+    Ok(())
+}
+
+/// Convert the 4 byte `src_fmt` pixels in `buf` to `dst_fmt` in place and truncate `buf` to
+/// the 3 byte pixels.
+///
+/// ```rust
+/// use image_swizzle::{shrink_vec, PixelFormat, PixelFormat24};
+/// let mut buf = vec![3, 2, 1, 255, 6, 5, 4, 255];
+/// shrink_vec(&mut buf, PixelFormat::Bgra, PixelFormat24::Rgb24).unwrap();
+/// assert_eq!(buf, [1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// Returns an error if `buf.len` is not multiple of a 4.
+pub fn shrink_vec(
+    buf: &mut Vec<u8>,
+    src_fmt: PixelFormat,
+    dst_fmt: PixelFormat24,
+) -> Result<(), SwizzleError> {
+    check_len(buf.len(), 4)?;
+    let kernel = packing(src_fmt, dst_fmt);
+    let pixels = buf.len() / 4;
+
+    let mut start = 0;
+    while start < pixels {
+        // The pixels `start..end` are written before the 4 byte pixels after `start`.
+        let end = (start * 4 / 3).min(pixels);
+        if end <= start {
+            let px: [u8; 4] = buf[start * 4..start * 4 + 4].try_into().unwrap();
+            kernel(&px, &mut buf[start * 3..start * 3 + 3]);
+            start += 1;
+            continue;
+        }
+        let (head, tail) = buf.split_at_mut(start * 4);
+        kernel(&tail[..(end - start) * 4], &mut head[start * 3..end * 3]);
+        start = end;
+    }
+    buf.truncate(pixels * 3);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LENGTHS: [usize; 9] = [0, 1, 2, 3, 4, 5, 17, 100, 1920 * 2 + 1];
+
+    #[test]
+    fn test_expand_vec() {
+        for pixels in LENGTHS {
+            let src = (0..pixels * 3).map(|i| i as u8).collect::<Vec<u8>>();
+            let mut expected = vec![0; pixels * 4];
+            crate::bgr24_to_argb(&src, &mut expected, 7);
+
+            let mut buf = Vec::with_capacity(pixels * 4);
+            buf.extend_from_slice(&src);
+            let ptr = buf.as_ptr();
+            expand_vec(&mut buf, PixelFormat24::Bgr24, PixelFormat::Argb, 7).unwrap();
+            assert_eq!(buf, expected, "{pixels} pixels");
+            assert_eq!(buf.as_ptr(), ptr);
+        }
+    }
+
+    #[test]
+    fn test_shrink_vec() {
+        for pixels in LENGTHS {
+            let src = (0..pixels * 4).map(|i| i as u8).collect::<Vec<u8>>();
+            let mut expected = vec![0; pixels * 3];
+            crate::xbgr_to_rgb24(&src, &mut expected);
+
+            let mut buf = src.clone();
+            shrink_vec(&mut buf, PixelFormat::Xbgr, PixelFormat24::Rgb24).unwrap();
+            assert_eq!(buf, expected, "{pixels} pixels");
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_all_formats() {
+        let src = (0..37 * 3).map(|i| i as u8).collect::<Vec<u8>>();
+        for format in PixelFormat::ALL {
+            for format24 in [PixelFormat24::Rgb24, PixelFormat24::Bgr24] {
+                let mut buf = src.clone();
+                expand_vec(&mut buf, format24, format, 255).unwrap();
+                shrink_vec(&mut buf, format, format24).unwrap();
+                assert_eq!(buf, src, "{format24:?} -> {format:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        assert_eq!(
+            expand_vec(
+                &mut vec![0; 4],
+                PixelFormat24::Rgb24,
+                PixelFormat::Rgba,
+                255
+            ),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 4,
+                pixel_size: 3
+            })
+        );
+        assert_eq!(
+            shrink_vec(&mut vec![0; 6], PixelFormat::Rgba, PixelFormat24::Rgb24),
+            Err(SwizzleError::LengthNotMultiple {
+                len: 6,
+                pixel_size: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Pixel formats of the VNC remote framebuffer (RFB) protocol.
+//!
+//! An RFB client negotiates its pixel format with a `SetPixelFormat` message, a server then has
+//! to send framebuffer updates in that format. [`encode`] converts the byte ordered pixels of a
+//! framebuffer to a negotiated format and [`decode`] converts them back.
+
+use crate::{ByteOrder, ChannelMasks, SwizzleError};
+
+/// The `PIXEL_FORMAT` structure of the RFB protocol, only true colour formats are supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PixelFormat {
+    pub bits_per_pixel: u8,
+    pub depth: u8,
+    pub big_endian: bool,
+    pub true_colour: bool,
+    pub red_max: u16,
+    pub green_max: u16,
+    pub blue_max: u16,
+    pub red_shift: u8,
+    pub green_shift: u8,
+    pub blue_shift: u8,
+}
+
+impl PixelFormat {
+    /// Parse the 16 bytes of a `PIXEL_FORMAT` as sent on the wire.
+    ///
+    /// ```rust
+    /// use image_swizzle::rfb;
+    /// let wire = [32, 24, 0, 1, 0, 255, 0, 255, 0, 255, 16, 8, 0, 0, 0, 0];
+    /// let format = rfb::PixelFormat::from_bytes(&wire);
+    /// assert_eq!(format.red_shift, 16);
+    /// assert_eq!(format.to_bytes(), wire);
+    /// ```
+    pub fn from_bytes(bytes: &[u8; 16]) -> Self {
+        let max = |i: usize| u16::from_be_bytes([bytes[i], bytes[i + 1]]);
+        Self {
+            bits_per_pixel: bytes[0],
+            depth: bytes[1],
+            big_endian: bytes[2] != 0,
+            true_colour: bytes[3] != 0,
+            red_max: max(4),
+            green_max: max(6),
+            blue_max: max(8),
+            red_shift: bytes[10],
+            green_shift: bytes[11],
+            blue_shift: bytes[12],
+        }
+    }
+
+    /// Returns the 16 bytes of the `PIXEL_FORMAT` as sent on the wire.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let [rh, rl] = self.red_max.to_be_bytes();
+        let [gh, gl] = self.green_max.to_be_bytes();
+        let [bh, bl] = self.blue_max.to_be_bytes();
+        [
+            self.bits_per_pixel,
+            self.depth,
+            self.big_endian as u8,
+            self.true_colour as u8,
+            rh,
+            rl,
+            gh,
+            gl,
+            bh,
+            bl,
+            self.red_shift,
+            self.green_shift,
+            self.blue_shift,
+            0,
+            0,
+            0,
+        ]
+    }
+
+    /// Returns the channel layout of the format, or an error if it is a colour map format or if
+    /// its channels overlap or do not fit in `bits_per_pixel`.
+    ///
+    /// ```rust
+    /// use image_swizzle::{rfb, SwizzleError};
+    /// let wire = [16, 16, 0, 1, 0, 31, 0, 63, 0, 31, 11, 5, 0, 0, 0, 0];
+    /// assert!(rfb::PixelFormat::from_bytes(&wire).channel_masks().is_ok());
+    /// let too_wide = [16, 16, 0, 1, 0, 255, 0, 63, 0, 31, 11, 5, 0, 0, 0, 0];
+    /// assert!(matches!(
+    ///     rfb::PixelFormat::from_bytes(&too_wide).channel_masks(),
+    ///     Err(SwizzleError::InvalidMasks { .. })
+    /// ));
+    /// ```
+    pub fn channel_masks(&self) -> Result<ChannelMasks, SwizzleError> {
+        if !self.true_colour {
+            return Err(SwizzleError::NotTrueColour);
+        }
+        // Masks that do not fit in 32 bits saturate so that validation rejects them.
+        let mask =
+            |max: u16, shift: u8| u32::try_from((max as u64) << shift.min(32)).unwrap_or(u32::MAX);
+        let byte_order = if self.big_endian {
+            ByteOrder::Big
+        } else {
+            ByteOrder::Little
+        };
+        let masks = ChannelMasks::new(
+            self.bits_per_pixel as u32,
+            mask(self.red_max, self.red_shift),
+            mask(self.green_max, self.green_shift),
+            mask(self.blue_max, self.blue_shift),
+            0,
+            byte_order,
+        );
+        masks.validate()?;
+        Ok(masks)
+    }
+}
+
+/// Convert `src` in the `src_fmt` format to the negotiated RFB `format` and store the result to
+/// `dst`.
+///
+/// 32 bits per pixel formats with 8 bit channels on byte boundaries are routed to the swizzle
+/// kernels.
+///
+/// ```rust
+/// use image_swizzle::{rfb, PixelFormat};
+/// let format = rfb::PixelFormat::from_bytes(&[32, 24, 1, 1, 0, 255, 0, 255, 0, 255, 16, 8, 0, 0, 0, 0]);
+/// let mut dst = [0; 4];
+/// rfb::encode(&[3, 2, 1, 255], PixelFormat::Bgrx, &mut dst, &format).unwrap();
+/// assert_eq!(dst[1..], [1, 2, 3]);
+/// ```
+///
+/// Returns an error if `format` is not supported, if `src.len` is not a multiple of 4 or if
+/// `dst` does not hold exactly as many pixels as `src`.
+pub fn encode(
+    src: &[u8],
+    src_fmt: crate::PixelFormat,
+    dst: &mut [u8],
+    format: &PixelFormat,
+) -> Result<(), SwizzleError> {
+    crate::convert_to_masks(src, src_fmt, dst, &format.channel_masks()?)
+}
+
+/// Convert `src` in the negotiated RFB `format` to `dst_fmt` and store the result to `dst`.
+///
+/// Returns an error if `format` is not supported, if `src.len` is not a multiple of the pixel
+/// size or if `dst` does not hold exactly as many pixels as `src`.
+pub fn decode(
+    src: &[u8],
+    format: &PixelFormat,
+    dst: &mut [u8],
+    dst_fmt: crate::PixelFormat,
+) -> Result<(), SwizzleError> {
+    crate::convert_from_masks(src, &format.channel_masks()?, dst, dst_fmt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[rustfmt::skip]
+    const BGR233: [u8; 16] = [8, 8, 0, 1, 0, 7, 0, 7, 0, 3, 0, 3, 6, 0, 0, 0];
+    #[rustfmt::skip]
+    const RGB565_BE: [u8; 16] = [16, 16, 1, 1, 0, 31, 0, 63, 0, 31, 11, 5, 0, 0, 0, 0];
+    #[rustfmt::skip]
+    const RGB888_LE: [u8; 16] = [32, 24, 0, 1, 0, 255, 0, 255, 0, 255, 16, 8, 0, 0, 0, 0];
+    #[rustfmt::skip]
+    const BGR888_BE: [u8; 16] = [32, 24, 1, 1, 0, 255, 0, 255, 0, 255, 0, 8, 16, 0, 0, 0];
+
+    #[test]
+    fn test_wire_roundtrip() {
+        for bytes in [BGR233, RGB565_BE, RGB888_LE, BGR888_BE] {
+            assert_eq!(PixelFormat::from_bytes(&bytes).to_bytes(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_fast_path() {
+        let masks = |bytes| PixelFormat::from_bytes(&bytes).channel_masks().unwrap();
+        assert_eq!(
+            masks(RGB888_LE).pixel_format(),
+            Some(crate::PixelFormat::Bgrx)
+        );
+        assert_eq!(
+            masks(BGR888_BE).pixel_format(),
+            Some(crate::PixelFormat::Xbgr)
+        );
+        assert_eq!(masks(RGB565_BE).pixel_format(), None);
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        // Red and cyan, full scale channels survive the narrow formats unchanged.
+        let cases: [([u8; 16], &[u8]); 4] = [
+            (BGR233, &[0x07, 0xf8]),
+            (RGB565_BE, &[0xf8, 0x00, 0x07, 0xff]),
+            (RGB888_LE, &[0, 0, 255, 255, 255, 255, 0, 255]),
+            (BGR888_BE, &[255, 0, 0, 255, 255, 255, 255, 0]),
+        ];
+        let bgrx = [0, 0, 255, 255, 255, 255, 0, 255];
+        let rgbx = [255, 0, 0, 255, 0, 255, 255, 255];
+        for (bytes, expected) in cases {
+            let format = PixelFormat::from_bytes(&bytes);
+            let mut dst = vec![0; expected.len()];
+            encode(&bgrx, crate::PixelFormat::Bgrx, &mut dst, &format).unwrap();
+            assert_eq!(dst, expected, "{format:?}");
+            encode(&rgbx, crate::PixelFormat::Rgbx, &mut dst, &format).unwrap();
+            assert_eq!(dst, expected, "{format:?}");
+
+            let mut decoded = [0; 8];
+            decode(&dst, &format, &mut decoded, crate::PixelFormat::Bgrx).unwrap();
+            assert_eq!(decoded, bgrx, "{format:?}");
+            decode(&dst, &format, &mut decoded, crate::PixelFormat::Rgbx).unwrap();
+            assert_eq!(decoded, rgbx, "{format:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_masks() {
+        let format = PixelFormat::from_bytes(&RGB565_BE);
+        let too_wide = PixelFormat {
+            red_shift: 12,
+            ..format
+        };
+        let overlapping = PixelFormat {
+            green_shift: 4,
+            ..format
+        };
+        let shifted_out = PixelFormat {
+            blue_shift: 40,
+            ..format
+        };
+        for format in [too_wide, overlapping, shifted_out] {
+            assert!(
+                matches!(
+                    format.channel_masks(),
+                    Err(SwizzleError::InvalidMasks { .. })
+                ),
+                "{format:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_colour_map() {
+        let format = PixelFormat {
+            true_colour: false,
+            ..PixelFormat::from_bytes(&BGR233)
+        };
+        assert_eq!(
+            encode(&[0; 4], crate::PixelFormat::Bgrx, &mut [0], &format),
+            Err(SwizzleError::NotTrueColour)
+        );
+    }
+}
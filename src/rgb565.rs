@@ -0,0 +1,65 @@
+//! Conversions between byte ordered 4 channel pixels and 16 bit 565 pixels, as used by
+//! embedded displays and some framebuffers.
+//!
+//! 565 formats are named by their channels from the most to the least significant bits, so
+//! `RGB565` has red in bits 15 to 11, green in bits 10 to 5 and blue in bits 4 to 0 like
+//! `DRM_FORMAT_RGB565`. The byte order of the 16 bit pixels in memory is given by a
+//! [`ByteOrder`](crate::ByteOrder).
+//!
+//! Unpacking repeats the high bits of every channel in its low bits, so the full range maps to
+//! `0..=255`, and packing rounds every channel to the nearest 5 or 6 bit value. Packing
+//! unpacked pixels gives back the same pixels.
+//!
+//! ```rust
+//! use image_swizzle::{rgb565, ByteOrder};
+//! let mut rgba = [0; 4];
+//! rgb565::rgb565_to_rgba(&0xfc10u16.to_le_bytes(), &mut rgba, ByteOrder::Little);
+//! assert_eq!(rgba, [255, 130, 132, 255]);
+//! ```
+
+macro_rules! impl_565_unpackings {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+        $(
+            #[doc = concat!(
+                "Convert ", stringify!($src), " pixels in `byte_order` to [`PixelFormat::",
+                stringify!($dst), "`](crate::PixelFormat::", stringify!($dst),
+                ") and store the result to `dst`."
+            )]
+            ///
+            /// Panics if `src.len` is not multiple of a 2 or if `dst.len` is not equal to
+            /// `src.len * 2`.
+            #[inline]
+            pub fn $name(src: &[u8], dst: &mut [u8], byte_order: crate::ByteOrder) {
+                #[cfg(feature = "nightly")]
+                crate::simd::rgb565::$name(src, dst, byte_order);
+                #[cfg(not(feature = "nightly"))]
+                crate::sisd::rgb565::$name(src, dst, byte_order);
+            }
+        )*
+    };
+}
+
+macro_rules! impl_565_packings {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+        $(
+            #[doc = concat!(
+                "Convert [`PixelFormat::", stringify!($src), "`](crate::PixelFormat::",
+                stringify!($src), ") data to ", stringify!($dst),
+                " pixels in `byte_order` and store the result to `dst`."
+            )]
+            ///
+            /// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to
+            /// `src.len / 2`.
+            #[inline]
+            pub fn $name(src: &[u8], dst: &mut [u8], byte_order: crate::ByteOrder) {
+                #[cfg(feature = "nightly")]
+                crate::simd::rgb565::$name(src, dst, byte_order);
+                #[cfg(not(feature = "nightly"))]
+                crate::sisd::rgb565::$name(src, dst, byte_order);
+            }
+        )*
+    };
+}
+
+crate::common::for_each_565_unpacking!(impl_565_unpackings);
+crate::common::for_each_565_packing!(impl_565_packings);
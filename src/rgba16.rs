@@ -0,0 +1,86 @@
+//! Swizzles of 16 bits per channel pixels, as stored by PNG, TIFF and farbfeld.
+//!
+//! The functions take the byte order of the source channels, 16 bit PNG and farbfeld store them
+//! in big endian. The output is always in native endian, filled channels are set to
+//! `u16::MAX`. [`from_u8`] and [`to_u8`] convert between 8 and 16 bit channels while
+//! reordering them like [`swizzle4`](crate::swizzle4).
+//!
+//! ```rust
+//! use image_swizzle::{rgba16, ByteOrder};
+//! let farbfeld = [0x0102u16, 0x0304, 0x0506, 0xffff].map(u16::to_be);
+//! let mut bgra = [0; 4];
+//! rgba16::rgba_to_bgra(&farbfeld, &mut bgra, ByteOrder::Big);
+//! assert_eq!(bgra, [0x0506, 0x0304, 0x0102, 0xffff]);
+//! ```
+
+use crate::common::impl_wide_wrappers;
+
+crate::common::for_each_wide_conversion!(impl_wide_wrappers { rgba16, u16, swap; });
+
+/// Swap the bytes of every channel of 4 channel data while overwriting the old data in `src`.
+///
+/// Panics if `src.len` is not multiple of a 4.
+#[inline]
+pub fn swap_bytes_inplace(src: &mut [u16]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgba16::swap_bytes_inplace(src);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgba16::swap_bytes_inplace(src);
+}
+
+/// Swap the bytes of every channel of 4 channel data and store the result to `dst`.
+///
+/// Panics if `src.len` is not multiple of a 4 or if `dst.len` is not equal to `src.len`.
+#[inline]
+pub fn swap_bytes(src: &[u16], dst: &mut [u16]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgba16::swap_bytes(src, dst);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgba16::swap_bytes(src, dst);
+}
+
+/// Convert 8 bit pixels to 16 bit pixels and store the result to `dst`.
+///
+/// Every channel is multiplied by 257 so 255 becomes `u16::MAX`. Channel `i` of every output
+/// pixel is taken from channel `order[i]` of the input pixel.
+///
+/// ```rust
+/// use image_swizzle::rgba16;
+/// let bgra = [1, 2, 128, 255];
+/// let mut rgba = [0; 4];
+/// rgba16::from_u8(&bgra, &mut rgba, [2, 1, 0, 3]);
+/// assert_eq!(rgba, [0x8080, 0x0202, 0x0101, 0xffff]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn from_u8(src: &[u8], dst: &mut [u16], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgba16::from_u8(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgba16::from_u8(src, dst, order);
+}
+
+/// Convert 16 bit pixels in `byte_order` to 8 bit pixels and store the result to `dst`.
+///
+/// Every channel is divided by 257 and rounded to the nearest integer. Channel `i` of every
+/// output pixel is taken from channel `order[i]` of the input pixel.
+///
+/// ```rust
+/// use image_swizzle::{rgba16, ByteOrder};
+/// let png = [0x0101u16, 0x7fff, 0x8080, 0xffff].map(u16::to_be);
+/// let mut bgra = [0; 4];
+/// rgba16::to_u8(&png, &mut bgra, [2, 1, 0, 3], ByteOrder::Big);
+/// assert_eq!(bgra, [128, 127, 1, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn to_u8(src: &[u16], dst: &mut [u8], order: [u8; 4], byte_order: crate::ByteOrder) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgba16::to_u8(src, dst, order, byte_order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgba16::to_u8(src, dst, order, byte_order);
+}
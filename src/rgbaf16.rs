@@ -0,0 +1,255 @@
+//! Swizzles and conversions of IEEE 754 half precision float pixels, as read back from
+//! `Rgba16Float` textures.
+//!
+//! The channels are stored as raw `u16` bits, filled channels are set to [`ONE`]. The
+//! conversions to and from `f32` and normalized `u8` reorder the channels like
+//! [`swizzle4`](crate::swizzle4) while converting.
+//!
+//! ```rust
+//! use image_swizzle::rgbaf16;
+//! let rgba = [0x3800, 0x3400, 0x0000, rgbaf16::ONE];
+//! let mut bgra = [0.0; 4];
+//! rgbaf16::to_f32(&rgba, &mut bgra, [2, 1, 0, 3]);
+//! assert_eq!(bgra, [0.0, 0.25, 0.5, 1.0]);
+//! ```
+
+use crate::common::impl_wide_wrappers;
+
+/// The bits of `1.0`.
+pub const ONE: u16 = 0x3c00;
+
+crate::common::for_each_wide_conversion!(impl_wide_wrappers { rgbaf16, u16, keep; });
+
+/// Convert the half float `bits` to `f32`, every half float is exactly representable.
+pub const fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exp = (bits >> 10 & 0x1f) as u32;
+    let man = (bits & 0x3ff) as u32;
+    let bits = match (exp, man) {
+        (0, 0) => sign,
+        (0, _) => {
+            // Subnormal, shift the leading one into the implicit bit.
+            let shift = man.leading_zeros() - 21;
+            sign | (113 - shift) << 23 | (man << shift & 0x3ff) << 13
+        }
+        (0x1f, _) => sign | 0x7f80_0000 | man << 13,
+        _ => sign | (exp + 112) << 23 | man << 13,
+    };
+    f32::from_bits(bits)
+}
+
+/// Convert `value` to the nearest half float, ties are rounded to even.
+///
+/// Values out of range become infinity and NaNs stay NaN.
+pub const fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16 & 0x8000) as u16;
+    let exp = (bits >> 23 & 0xff) as i32;
+    let man = bits & 0x7f_ffff;
+    if exp == 0xff {
+        let nan = if man != 0 {
+            0x200 | (man >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exp = exp - 112;
+    if exp >= 0x1f {
+        return sign | 0x7c00;
+    }
+    // Normal halves keep the exponent and drop 13 mantissa bits, subnormal halves also shift
+    // the implicit bit into the mantissa. A carry out of the mantissa increments the exponent.
+    let (full, shift) = if exp > 0 {
+        (man, 13)
+    } else if exp > -11 {
+        (man | 0x80_0000, (14 - exp) as u32)
+    } else {
+        return sign;
+    };
+    let half = 1 << (shift - 1);
+    let rem = full & ((1 << shift) - 1);
+    let mut rounded = full >> shift;
+    if rem > half || (rem == half && rounded & 1 == 1) {
+        rounded += 1;
+    }
+    let exp = if exp > 0 { (exp as u32) << 10 } else { 0 };
+    sign | (exp + rounded) as u16
+}
+
+/// Convert half float pixels to `f32` and store the result to `dst`.
+///
+/// Channel `i` of every output pixel is taken from channel `order[i]` of the input pixel.
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn to_f32(src: &[u16], dst: &mut [f32], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf16::to_f32(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf16::to_f32(src, dst, order);
+}
+
+/// Convert `f32` pixels to the nearest half floats and store the result to `dst`.
+///
+/// Channel `i` of every output pixel is taken from channel `order[i]` of the input pixel.
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn from_f32(src: &[f32], dst: &mut [u16], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf16::from_f32(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf16::from_f32(src, dst, order);
+}
+
+/// Convert half float pixels to normalized `u8` and store the result to `dst`.
+///
+/// Channels outside of `0.0..=1.0` are clamped and NaNs become 0. Channel `i` of every output
+/// pixel is taken from channel `order[i]` of the input pixel.
+///
+/// ```rust
+/// use image_swizzle::rgbaf16;
+/// let rgba = [rgbaf16::ONE, 0x3800, 0xbc00, rgbaf16::ONE];
+/// let mut bgra = [0; 4];
+/// rgbaf16::to_u8(&rgba, &mut bgra, [2, 1, 0, 3]);
+/// assert_eq!(bgra, [0, 128, 255, 255]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn to_u8(src: &[u16], dst: &mut [u8], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf16::to_u8(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf16::to_u8(src, dst, order);
+}
+
+/// Convert normalized `u8` pixels to half floats and store the result to `dst`.
+///
+/// Channel `i` of every output pixel is taken from channel `order[i]` of the input pixel.
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn from_u8(src: &[u8], dst: &mut [u16], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf16::from_u8(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf16::from_u8(src, dst, order);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f16_to_f32() {
+        let cases = [
+            (0x0000, 0.0),
+            (0x8000, -0.0),
+            (ONE, 1.0),
+            (0xc000, -2.0),
+            (0x3555, 0.333_251_95),
+            (0x7bff, 65504.0),
+            (0x0400, 2f32.powi(-14)),
+            (0x0001, 2f32.powi(-24)),
+            (0x03ff, 1023.0 * 2f32.powi(-24)),
+            (0x7c00, f32::INFINITY),
+            (0xfc00, f32::NEG_INFINITY),
+        ];
+        for (bits, value) in cases {
+            assert_eq!(f16_to_f32(bits).to_bits(), value.to_bits(), "{bits:#06x}");
+        }
+        assert!(f16_to_f32(0x7e00).is_nan());
+    }
+
+    #[test]
+    fn test_f32_to_f16_roundtrip() {
+        for bits in 0..=u16::MAX {
+            let value = f16_to_f32(bits);
+            if value.is_nan() {
+                assert!(f16_to_f32(f32_to_f16(value)).is_nan());
+            } else {
+                assert_eq!(f32_to_f16(value), bits, "{bits:#06x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_to_f16_rounding() {
+        let cases = [
+            // Ties between 1.0 and the next half round to even.
+            (1.0 + 2f32.powi(-11), ONE),
+            (1.0 + 3.0 * 2f32.powi(-11), 0x3c02),
+            (1.0 + 2f32.powi(-11) + 2f32.powi(-20), 0x3c01),
+            // Rounding up the largest mantissa carries into the exponent.
+            (65519.0, 0x7bff),
+            (65520.0, 0x7c00),
+            (1e10, 0x7c00),
+            (2f32.powi(-25), 0x0000),
+            (2f32.powi(-25) + 2f32.powi(-40), 0x0001),
+            (3.0 * 2f32.powi(-25), 0x0002),
+            (1023.5 * 2f32.powi(-24), 0x0400),
+            (2f32.powi(-30), 0x0000),
+            (-2f32.powi(-30), 0x8000),
+        ];
+        for (value, bits) in cases {
+            assert_eq!(f32_to_f16(value), bits, "{value:e}");
+        }
+        assert_eq!(f32_to_f16(f32::NAN) & 0x7e00, 0x7e00);
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let halves: Vec<u16> = (0..=u16::MAX).collect();
+        let mut floats = vec![0.0; halves.len()];
+        to_f32(&halves, &mut floats, [0, 1, 2, 3]);
+        for (&bits, value) in halves.iter().zip(&floats) {
+            assert_eq!(value.to_bits(), f16_to_f32(bits).to_bits(), "{bits:#06x}");
+        }
+
+        let floats: Vec<f32> = (0..=u32::MAX).step_by(997).map(f32::from_bits).collect();
+        let floats = &floats[..floats.len() / 4 * 4];
+        let mut halves = vec![0; floats.len()];
+        from_f32(floats, &mut halves, [0, 1, 2, 3]);
+        for (&value, &bits) in floats.iter().zip(&halves) {
+            assert_eq!(bits, f32_to_f16(value), "{:#010x}", value.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_to_f32() {
+        let argb = [ONE, 0x3800, 0x3400, 0x0000].repeat(5);
+        let mut rgba = vec![0.0; argb.len()];
+        to_f32(&argb, &mut rgba, [1, 2, 3, 0]);
+        assert_eq!(rgba, [0.5, 0.25, 0.0, 1.0].repeat(5));
+
+        let mut roundtrip = vec![0; argb.len()];
+        from_f32(&rgba, &mut roundtrip, [3, 0, 1, 2]);
+        assert_eq!(roundtrip, argb);
+    }
+
+    #[test]
+    fn test_u8_roundtrip() {
+        let src: Vec<u8> = (0..=255).collect();
+        let mut halves = vec![0; src.len()];
+        from_u8(&src, &mut halves, [0, 1, 2, 3]);
+        let mut dst = vec![0; src.len()];
+        to_u8(&halves, &mut dst, [0, 1, 2, 3]);
+        assert_eq!(dst, src);
+
+        from_u8(&[0, 51, 255, 128], &mut halves[..4], [2, 1, 0, 3]);
+        assert_eq!(halves[..4], [ONE, 0x3266, 0x0000, 0x3804]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_panic_invalid_order() {
+        to_f32(&[0; 4], &mut [0.0; 4], [0, 1, 2, 4]);
+    }
+}
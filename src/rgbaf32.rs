@@ -0,0 +1,86 @@
+//! Swizzles of `f32` channel pixels, as used by linear light rendering pipelines.
+//!
+//! The swizzles reorder the channels as they are, filled channels are set to `1.0`. The
+//! conversions to and from normalized `u8` reorder the channels like
+//! [`swizzle4`](crate::swizzle4) in the same pass.
+//!
+//! ```rust
+//! use image_swizzle::rgbaf32;
+//! let rgba = [0.25f32, 0.5, 0.75, 1.0];
+//! let mut bgra = [0.0; 4];
+//! rgbaf32::rgba_to_bgra(&rgba, &mut bgra);
+//! assert_eq!(bgra, [0.75, 0.5, 0.25, 1.0]);
+//! ```
+
+use crate::common::impl_wide_wrappers;
+
+crate::common::for_each_wide_conversion!(impl_wide_wrappers { rgbaf32, f32, keep; });
+
+/// Convert normalized `u8` pixels to `f32` in `0.0..=1.0` and store the result to `dst`.
+///
+/// Channel `i` of every output pixel is taken from channel `order[i]` of the input pixel.
+///
+/// ```rust
+/// use image_swizzle::rgbaf32;
+/// let bgra = [0, 51, 255, 255];
+/// let mut rgba = [0.0; 4];
+/// rgbaf32::from_u8(&bgra, &mut rgba, [2, 1, 0, 3]);
+/// assert_eq!(rgba, [1.0, 0.2, 0.0, 1.0]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn from_u8(src: &[u8], dst: &mut [f32], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf32::from_u8(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf32::from_u8(src, dst, order);
+}
+
+/// Convert normalized `u8` pixels to `f32` and standardize them with `mean` and `std` as
+/// expected by most image models, then store the result to `dst`.
+///
+/// Channel `i` of every output pixel is `(src[order[i]] / 255 - mean[i]) / std[i]`, so `mean`
+/// and `std` are in the output channel order.
+///
+/// ```rust
+/// use image_swizzle::rgbaf32;
+/// let bgra = [0, 51, 255, 255];
+/// let mut rgba = [0.0; 4];
+/// let (mean, std) = ([0.5, 0.5, 0.5, 0.0], [0.5, 0.5, 0.5, 1.0]);
+/// rgbaf32::from_u8_normalized(&bgra, &mut rgba, [2, 1, 0, 3], mean, std);
+/// assert_eq!(rgba, [1.0, -0.6, -1.0, 1.0]);
+/// ```
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn from_u8_normalized(
+    src: &[u8],
+    dst: &mut [f32],
+    order: [u8; 4],
+    mean: [f32; 4],
+    std: [f32; 4],
+) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf32::from_u8_normalized(src, dst, order, mean, std);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf32::from_u8_normalized(src, dst, order, mean, std);
+}
+
+/// Convert `f32` pixels in `0.0..=1.0` to normalized `u8` and store the result to `dst`.
+///
+/// Channels are rounded to the nearest `u8`, values outside of `0.0..=1.0` are clamped and
+/// NaNs become 0. Channel `i` of every output pixel is taken from channel `order[i]` of the
+/// input pixel.
+///
+/// Panics if `src.len` is not multiple of a 4, if `dst.len` is not equal to `src.len` or if
+/// any index in `order` is greater than 3.
+#[inline]
+pub fn to_u8(src: &[f32], dst: &mut [u8], order: [u8; 4]) {
+    #[cfg(feature = "nightly")]
+    crate::simd::rgbaf32::to_u8(src, dst, order);
+    #[cfg(not(feature = "nightly"))]
+    crate::sisd::rgbaf32::to_u8(src, dst, order);
+}
@@ -1,4 +1,5 @@
-use std::simd::{self, simd_swizzle, u8x16, u8x4};
+use std::simd::num::SimdUint;
+use std::simd::{self, simd_swizzle, u8x16, u8x4, Simd, Swizzle};
 
 #[rustfmt::skip]
 macro_rules! idx_order {
@@ -26,11 +27,23 @@ const RGBA32_TO_RGBA_SWIZZLE_IDXS: [usize; VECTOR_WIDTH] = idx_order!(3, 2, 1, 0
 #[cfg(target_endian = "little")]
 const RGBA32_TO_RGBA_SWIZZLE_IDXS_SHORT: [usize; 4] = [3, 2, 1, 0];
 
-const ARGB_TO_RGBA_SWIZZLE_IDXS: [usize; VECTOR_WIDTH] = idx_order!(1, 2, 3, 0);
-const ARGB_TO_RGBA_SWIZZLE_IDXS_SHORT: [usize; 4] = [1, 2, 3, 0];
-
 const BGRA_TO_RGBA_SWIZZLE_IDXS: [usize; VECTOR_WIDTH] = idx_order!(2, 1, 0, 3);
 const BGRA_TO_RGBA_SWIZZLE_IDXS_SHORT: [usize; 4] = [2, 1, 0, 3];
+/// 16 RGB24 pixels.
+const RGB24_BLOCK: usize = 48;
+const RGB24_TO_BGR24_SWIZZLE_IDXS: [usize; RGB24_BLOCK] = idx_swap_rb();
+
+/// Swizzle indices that swap the first and the last channel of every 3 byte pixel.
+const fn idx_swap_rb<const N: usize>() -> [usize; N] {
+    let mut idxs = [0; N];
+    let mut i = 0;
+    while i < N {
+        idxs[i] = i - i % 3 + 2 - i % 3;
+        i += 1;
+    }
+    idxs
+}
+
 #[rustfmt::skip]
 const XXX0_TO_XXXX_OR: u8x16 = u8x16::from_array([
     0u8, 0u8, 0u8, 255u8,
@@ -40,6 +53,134 @@ const XXX0_TO_XXXX_OR: u8x16 = u8x16::from_array([
 ]);
 const XXX0_TO_XXXX_OR_SHORT: u8x4 = u8x4::from_array([0u8, 0u8, 0u8, 255u8]);
 
+/// Compile time channel order, `A`, `B`, `C` and `D` are the source channels of the output
+/// channels.
+struct Order<const A: usize, const B: usize, const C: usize, const D: usize>;
+
+impl<const A: usize, const B: usize, const C: usize, const D: usize> Swizzle<VECTOR_WIDTH>
+    for Order<A, B, C, D>
+{
+    const INDEX: [usize; VECTOR_WIDTH] = idx_order!(A, B, C, D);
+}
+
+impl<const A: usize, const B: usize, const C: usize, const D: usize> Swizzle<4>
+    for Order<A, B, C, D>
+{
+    const INDEX: [usize; 4] = [A, B, C, D];
+}
+
+#[rustfmt::skip]
+macro_rules! idx_expand {
+    ($a:expr, $b:expr, $c:expr, $d:expr) => {
+        [
+            expand_idx!($a, 0), expand_idx!($b, 0), expand_idx!($c, 0), expand_idx!($d, 0),
+            expand_idx!($a, 1), expand_idx!($b, 1), expand_idx!($c, 1), expand_idx!($d, 1),
+            expand_idx!($a, 2), expand_idx!($b, 2), expand_idx!($c, 2), expand_idx!($d, 2),
+            expand_idx!($a, 3), expand_idx!($b, 3), expand_idx!($c, 3), expand_idx!($d, 3),
+        ]
+    }
+}
+
+/// Index of channel `$c` of the 3 byte pixel `$px` in a vector of `$width` lanes, channel 3 is
+/// the first lane of the fill vector.
+macro_rules! expand_idx {
+    ($c:expr, $px:expr) => {
+        expand_idx!($c, $px, VECTOR_WIDTH)
+    };
+    ($c:expr, $px:expr, $width:expr) => {
+        if $c == 3 {
+            $width
+        } else {
+            $c + 3 * $px
+        }
+    };
+}
+
+/// Compile time 3 to 4 channel expansion, `A`, `B`, `C` and `D` are the source channels of the
+/// output channels where 3 is the fill value.
+struct Expand<const A: usize, const B: usize, const C: usize, const D: usize>;
+
+impl<const A: usize, const B: usize, const C: usize, const D: usize> Swizzle<VECTOR_WIDTH>
+    for Expand<A, B, C, D>
+{
+    const INDEX: [usize; VECTOR_WIDTH] = idx_expand!(A, B, C, D);
+}
+
+impl<const A: usize, const B: usize, const C: usize, const D: usize> Swizzle<4>
+    for Expand<A, B, C, D>
+{
+    const INDEX: [usize; 4] = [
+        expand_idx!(A, 0, 4),
+        expand_idx!(B, 0, 4),
+        expand_idx!(C, 0, 4),
+        expand_idx!(D, 0, 4),
+    ];
+}
+
+#[rustfmt::skip]
+macro_rules! idx_pack {
+    ($a:expr, $b:expr, $c:expr) => {
+        [
+            $a          , $b          , $c          ,
+            $a +  4     , $b +  4     , $c +  4     ,
+            $a + (4 * 2), $b + (4 * 2), $c + (4 * 2),
+            $a + (4 * 3), $b + (4 * 3), $c + (4 * 3),
+            0, 0, 0, 0,
+        ]
+    }
+}
+
+/// Compile time 4 to 3 channel packing, `A`, `B` and `C` are the source channels of the output
+/// channels. The last 4 lanes of a full vector are unused.
+struct Pack<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> Swizzle<VECTOR_WIDTH> for Pack<A, B, C> {
+    const INDEX: [usize; VECTOR_WIDTH] = idx_pack!(A, B, C);
+}
+
+impl<const A: usize, const B: usize, const C: usize> Swizzle<4> for Pack<A, B, C> {
+    const INDEX: [usize; 4] = [A, B, C, 0];
+}
+
+/// Swizzle indices of `N` lanes of 4 channel pixels, where `order` is the source channel of
+/// each output channel and the source channel `fill` is taken from the second vector.
+const fn idx_lanes<const N: usize>(order: [usize; 4], fill: usize) -> [usize; N] {
+    let mut idxs = [0; N];
+    let mut i = 0;
+    while i < N {
+        let c = order[i % 4];
+        idxs[i] = if c == fill { N } else { i - i % 4 + c };
+        i += 1;
+    }
+    idxs
+}
+
+/// Compile time channel order for vectors of any length and channel type, `A`, `B`, `C` and
+/// `D` are the source channels of the output channels and the source channel `FILL` is
+/// replaced, 4 replaces none.
+struct Lanes<const A: usize, const B: usize, const C: usize, const D: usize, const FILL: usize>;
+
+impl<
+        const A: usize,
+        const B: usize,
+        const C: usize,
+        const D: usize,
+        const FILL: usize,
+        const N: usize,
+    > Swizzle<N> for Lanes<A, B, C, D, FILL>
+{
+    const INDEX: [usize; N] = idx_lanes([A, B, C, D], FILL);
+}
+
+macro_rules! fill_lane {
+    () => {
+        4
+    };
+    ($lane:literal) => {
+        $lane
+    };
+}
+
 macro_rules! swizzle_4_wide {
     ($src:expr, $dst:expr, $idxs:expr, $idxs_short:expr) => {
         assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
@@ -57,6 +198,71 @@ macro_rules! swizzle_4_wide {
     };
 }
 
+macro_rules! swap_rb_3_wide {
+    ($src:expr, $dst:expr) => {
+        assert!($src.len() % 3 == 0 && $src.len() == $dst.len());
+
+        let end = ($src.len() / RGB24_BLOCK) * RGB24_BLOCK;
+        (0..end).step_by(RGB24_BLOCK).for_each(|i| {
+            simd_swizzle!(
+                Simd::<u8, RGB24_BLOCK>::from_slice(&$src[i..i + RGB24_BLOCK]),
+                RGB24_TO_BGR24_SWIZZLE_IDXS
+            )
+            .copy_to_slice(&mut $dst[i..i + RGB24_BLOCK]);
+        });
+
+        (end..$src.len()).step_by(3).for_each(|i| {
+            let (r, g, b) = ($src[i], $src[i + 1], $src[i + 2]);
+            $dst[i] = b;
+            $dst[i + 1] = g;
+            $dst[i + 2] = r;
+        });
+    };
+}
+
+macro_rules! swizzle_order_4_wide {
+    ($src:expr, $dst:expr, $order:ty) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
+
+        let end = ($src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            <$order as Swizzle<VECTOR_WIDTH>>::swizzle(u8x16::from_slice(
+                &$src[i..i + VECTOR_WIDTH],
+            ))
+            .copy_to_slice(&mut $dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..$src.len()).step_by(4).for_each(|i| {
+            <$order as Swizzle<4>>::swizzle(u8x4::from_slice(&$src[i..i + 4]))
+                .copy_to_slice(&mut $dst[i..i + 4]);
+        });
+    };
+}
+
+macro_rules! swizzle_dyn_4_wide {
+    ($src:expr, $dst:expr, $order:expr) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
+        assert!($order.iter().all(|&i| i < 4));
+
+        let [a, b, c, d] = $order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array([a, b, c, d]);
+
+        let end = ($src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            u8x16::from_slice(&$src[i..i + VECTOR_WIDTH])
+                .swizzle_dyn(idxs)
+                .copy_to_slice(&mut $dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..$src.len()).step_by(4).for_each(|i| {
+            u8x4::from_slice(&$src[i..i + 4])
+                .swizzle_dyn(idxs_short)
+                .copy_to_slice(&mut $dst[i..i + 4]);
+        });
+    };
+}
+
 macro_rules! apply_mask_4_wide {
     ($src:expr, $dst:expr, $mask:expr, $mask_short:expr, $or:expr, $or_short:expr) => {
         assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
@@ -106,6 +312,260 @@ macro_rules! apply_x_mask_and_swizzle_4_wide {
     };
 }
 
+macro_rules! fill_lane_4_wide {
+    ($src:expr, $dst:expr, $lane:expr, $value:expr) => {
+        assert!($lane < 4);
+
+        let mask =
+            simd::Mask::<i8, VECTOR_WIDTH>::from_array(std::array::from_fn(|i| i % 4 != $lane));
+        let mask_short = simd::Mask::<i8, 4>::from_array(std::array::from_fn(|i| i != $lane));
+        apply_mask_4_wide!(
+            $src,
+            $dst,
+            mask,
+            mask_short,
+            u8x16::splat($value),
+            u8x4::splat($value)
+        );
+    };
+}
+
+macro_rules! fill_and_swizzle_4_wide {
+    ($src:expr, $dst:expr, $lane:expr, $value:expr, $order:ty) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
+
+        let mask =
+            simd::Mask::<i8, VECTOR_WIDTH>::from_array(std::array::from_fn(|i| i % 4 != $lane));
+        let mask_short = simd::Mask::<i8, 4>::from_array(std::array::from_fn(|i| i != $lane));
+        let or = u8x16::splat($value);
+        let or_short = u8x4::splat($value);
+
+        let end = ($src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            <$order as Swizzle<VECTOR_WIDTH>>::swizzle(u8x16::load_select(
+                &$src[i..i + VECTOR_WIDTH],
+                mask,
+                or,
+            ))
+            .copy_to_slice(&mut $dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..$src.len()).step_by(4).for_each(|i| {
+            <$order as Swizzle<4>>::swizzle(u8x4::load_select(
+                &$src[i..i + 4],
+                mask_short,
+                or_short,
+            ))
+            .copy_to_slice(&mut $dst[i..i + 4]);
+        });
+    };
+}
+
+macro_rules! fill_and_swizzle_dyn_4_wide {
+    ($src:expr, $dst:expr, $lane:expr, $value:expr, $order:expr) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
+        assert!($order.iter().all(|&i| i < 4) && $lane < 4);
+
+        let [a, b, c, d] = $order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array([a, b, c, d]);
+        let mask =
+            simd::Mask::<i8, VECTOR_WIDTH>::from_array(std::array::from_fn(|i| i % 4 != $lane));
+        let mask_short = simd::Mask::<i8, 4>::from_array(std::array::from_fn(|i| i != $lane));
+        let or = u8x16::splat($value);
+        let or_short = u8x4::splat($value);
+
+        let end = ($src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            u8x16::load_select(&$src[i..i + VECTOR_WIDTH], mask, or)
+                .swizzle_dyn(idxs)
+                .copy_to_slice(&mut $dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..$src.len()).step_by(4).for_each(|i| {
+            u8x4::load_select(&$src[i..i + 4], mask_short, or_short)
+                .swizzle_dyn(idxs_short)
+                .copy_to_slice(&mut $dst[i..i + 4]);
+        });
+    };
+}
+
+macro_rules! expand_3_to_4_wide {
+    ($src:expr, $dst:expr, $fill:expr, $order:ty) => {
+        assert!($src.len() % 3 == 0 && $src.len() / 3 * 4 == $dst.len());
+
+        let fill = u8x16::splat($fill);
+        let fill_short = u8x4::splat($fill);
+
+        // Every vector reads 16 bytes but only uses 12 of them, stop while the last read still
+        // fits in `src`.
+        let blocks = $src.len().saturating_sub(4) / 12;
+        (0..blocks).for_each(|b| {
+            let (i, j) = (b * 12, b * VECTOR_WIDTH);
+            <$order as Swizzle<VECTOR_WIDTH>>::concat_swizzle(
+                u8x16::from_slice(&$src[i..i + VECTOR_WIDTH]),
+                fill,
+            )
+            .copy_to_slice(&mut $dst[j..j + VECTOR_WIDTH]);
+        });
+
+        (blocks * 12..$src.len())
+            .step_by(3)
+            .zip((blocks * VECTOR_WIDTH..).step_by(4))
+            .for_each(|(i, j)| {
+                let px = u8x4::from_array([$src[i], $src[i + 1], $src[i + 2], 0]);
+                <$order as Swizzle<4>>::concat_swizzle(px, fill_short)
+                    .copy_to_slice(&mut $dst[j..j + 4]);
+            });
+    };
+}
+
+macro_rules! pack_4_to_3_wide {
+    ($src:expr, $dst:expr, $order:ty) => {
+        assert!($src.len() % 4 == 0 && $src.len() / 4 * 3 == $dst.len());
+
+        // Every vector writes 16 bytes of which only 12 are used, the rest is overwritten by
+        // the next vector or the tail. Stop while the last write still fits in `dst`.
+        let blocks = $dst.len().saturating_sub(4) / 12;
+        (0..blocks).for_each(|b| {
+            let (i, j) = (b * VECTOR_WIDTH, b * 12);
+            <$order as Swizzle<VECTOR_WIDTH>>::swizzle(u8x16::from_slice(
+                &$src[i..i + VECTOR_WIDTH],
+            ))
+            .copy_to_slice(&mut $dst[j..j + VECTOR_WIDTH]);
+        });
+
+        (blocks * VECTOR_WIDTH..$src.len())
+            .step_by(4)
+            .zip((blocks * 12..).step_by(3))
+            .for_each(|(i, j)| {
+                let px = <$order as Swizzle<4>>::swizzle(u8x4::from_slice(&$src[i..i + 4]));
+                $dst[j..j + 3].copy_from_slice(&px.as_array()[..3]);
+            });
+    };
+}
+
+macro_rules! swizzle_lanes_4_wide {
+    ($src:expr, $dst:expr, $vec:ident, $short:ident, $order:ty, $fill:expr $(, $swap:expr)?) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
+
+        const WIDTH: usize = $vec::LEN;
+        let fill = $vec::splat($fill);
+        let fill_short = $short::splat($fill);
+
+        let end = ($src.len() / WIDTH) * WIDTH;
+        (0..end).step_by(WIDTH).for_each(|i| {
+            let v = $vec::from_slice(&$src[i..i + WIDTH]);
+            $(let v = if $swap { v.swap_bytes() } else { v };)?
+            <$order as Swizzle<WIDTH>>::concat_swizzle(v, fill)
+                .copy_to_slice(&mut $dst[i..i + WIDTH]);
+        });
+
+        (end..$src.len()).step_by(4).for_each(|i| {
+            let v = $short::from_slice(&$src[i..i + 4]);
+            $(let v = if $swap { v.swap_bytes() } else { v };)?
+            <$order as Swizzle<4>>::concat_swizzle(v, fill_short)
+                .copy_to_slice(&mut $dst[i..i + 4]);
+        });
+    };
+}
+
+/// Implements [`crate::common::for_each_wide_conversion`] for `$elem` channels. With `swap` the
+/// functions take the byte order of the source, with `keep` the source is in native endian.
+macro_rules! impl_wide_conversions {
+    (
+        $vec:ident, $short:ident, $elem:ty, $fill:expr, $swap:ident;
+        $($name:ident, $inplace:ident, $src:ident => $dst:ident, [$a:literal, $b:literal, $c:literal, $d:literal] $(, $lane:literal)?;)*
+    ) => {
+        $(
+            impl_wide_conversions!(
+                @fn $swap, $vec, $short, $elem, $fill, $name, $inplace,
+                Lanes<$a, $b, $c, $d, { fill_lane!($($lane)?) }>
+            );
+        )*
+    };
+    (@fn swap, $vec:ident, $short:ident, $elem:ty, $fill:expr, $name:ident, $inplace:ident, $order:ty) => {
+        #[inline(always)]
+        pub fn $inplace(src: &mut [$elem], byte_order: crate::ByteOrder) {
+            let swap = byte_order != crate::ByteOrder::NATIVE;
+            swizzle_lanes_4_wide!(src, src, $vec, $short, $order, $fill, swap);
+        }
+
+        #[inline(always)]
+        pub fn $name(src: &[$elem], dst: &mut [$elem], byte_order: crate::ByteOrder) {
+            let swap = byte_order != crate::ByteOrder::NATIVE;
+            swizzle_lanes_4_wide!(src, dst, $vec, $short, $order, $fill, swap);
+        }
+    };
+    (@fn keep, $vec:ident, $short:ident, $elem:ty, $fill:expr, $name:ident, $inplace:ident, $order:ty) => {
+        #[inline(always)]
+        pub fn $inplace(src: &mut [$elem]) {
+            swizzle_lanes_4_wide!(src, src, $vec, $short, $order, $fill);
+        }
+
+        #[inline(always)]
+        pub fn $name(src: &[$elem], dst: &mut [$elem]) {
+            swizzle_lanes_4_wide!(src, dst, $vec, $short, $order, $fill);
+        }
+    };
+}
+
+macro_rules! impl_packings {
+    ($($name:ident, $src:ident => $dst:ident, [$a:literal, $b:literal, $c:literal];)*) => {
+        $(
+            #[inline(always)]
+            pub fn $name(src: &[u8], dst: &mut [u8]) {
+                pack_4_to_3_wide!(src, dst, Pack<$a, $b, $c>);
+            }
+        )*
+    };
+}
+
+macro_rules! impl_expansions {
+    ($($name:ident, $src:ident => $dst:ident, [$a:literal, $b:literal, $c:literal, $d:literal] $(, $alpha:ident)?;)*) => {
+        $(
+            impl_expansions!(@fn $name, Expand<$a, $b, $c, $d> $(, $alpha)?);
+        )*
+    };
+    (@fn $name:ident, $order:ty, alpha) => {
+        #[inline(always)]
+        pub fn $name(src: &[u8], dst: &mut [u8], alpha: u8) {
+            expand_3_to_4_wide!(src, dst, alpha, $order);
+        }
+    };
+    (@fn $name:ident, $order:ty) => {
+        #[inline(always)]
+        pub fn $name(src: &[u8], dst: &mut [u8]) {
+            expand_3_to_4_wide!(src, dst, 255, $order);
+        }
+    };
+}
+
+macro_rules! convert_4_wide {
+    ($src:expr, $dst:expr, [$a:literal, $b:literal, $c:literal, $d:literal]) => {
+        swizzle_order_4_wide!($src, $dst, Order<$a, $b, $c, $d>);
+    };
+    ($src:expr, $dst:expr, [$a:literal, $b:literal, $c:literal, $d:literal], $lane:literal) => {
+        fill_and_swizzle_4_wide!($src, $dst, $lane, 255, Order<$a, $b, $c, $d>);
+    };
+}
+
+macro_rules! impl_conversions {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*) => {
+        $(
+            #[inline(always)]
+            pub fn $inplace(src: &mut [u8]) {
+                convert_4_wide!(src, src, [$($order),*] $(, $lane)?);
+            }
+
+            #[inline(always)]
+            pub fn $name(src: &[u8], dst: &mut [u8]) {
+                convert_4_wide!(src, dst, [$($order),*] $(, $lane)?);
+            }
+        )*
+    };
+}
+
 #[inline(always)]
 pub fn rgba_to_bgra_inplace(src: &mut [u8]) {
     swizzle_4_wide!(
@@ -159,6 +619,50 @@ pub fn rgba32_to_rgba_inplace(src: &mut [u8]) {
             RGBA32_TO_RGBA_SWIZZLE_IDXS_SHORT
         );
     }
+    #[cfg(target_endian = "big")]
+    {
+        assert!(src.len().is_multiple_of(4));
+    }
+}
+
+#[inline(always)]
+pub fn rgba32_to_bgra(src: &[u8], dst: &mut [u8]) {
+    #[cfg(target_endian = "little")]
+    {
+        swizzle_4_wide!(
+            src,
+            dst,
+            RGBA32_TO_BGRA_SWIZZLE_IDXS,
+            RGBA32_TO_BGRA_SWIZZLE_IDXS_SHORT
+        );
+    }
+    #[cfg(target_endian = "big")]
+    {
+        swizzle_4_wide!(
+            src,
+            dst,
+            RGBA_TO_BGRA_SWIZZLE_IDXS,
+            RGBA_TO_BGRA_SWIZZLE_IDXS_SHORT
+        );
+    }
+}
+
+#[inline(always)]
+pub fn rgba32_to_rgba(src: &[u8], dst: &mut [u8]) {
+    #[cfg(target_endian = "little")]
+    {
+        swizzle_4_wide!(
+            src,
+            dst,
+            RGBA32_TO_RGBA_SWIZZLE_IDXS,
+            RGBA32_TO_RGBA_SWIZZLE_IDXS_SHORT
+        );
+    }
+    #[cfg(target_endian = "big")]
+    {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        dst.copy_from_slice(src);
+    }
 }
 
 #[inline(always)]
@@ -310,13 +814,585 @@ pub fn bgr0_to_rgbx(src: &[u8], dst: &mut [u8]) {
 }
 
 #[inline(always)]
-pub fn argb_to_rgba_inplace(src: &mut [u8]) {
-    swizzle_4_wide!(
-        src,
-        src,
-        ARGB_TO_RGBA_SWIZZLE_IDXS,
-        ARGB_TO_RGBA_SWIZZLE_IDXS_SHORT
-    );
+pub fn rgb24_to_bgr24_inplace(src: &mut [u8]) {
+    swap_rb_3_wide!(src, src);
+}
+
+#[inline(always)]
+pub fn rgb24_to_bgr24(src: &[u8], dst: &mut [u8]) {
+    swap_rb_3_wide!(src, dst);
+}
+
+#[inline(always)]
+pub fn bgr24_to_rgb24_inplace(src: &mut [u8]) {
+    swap_rb_3_wide!(src, src);
+}
+
+#[inline(always)]
+pub fn bgr24_to_rgb24(src: &[u8], dst: &mut [u8]) {
+    swap_rb_3_wide!(src, dst);
+}
+
+#[inline(always)]
+pub fn fill_lane_inplace(src: &mut [u8], lane: usize, value: u8) {
+    fill_lane_4_wide!(src, src, lane, value);
+}
+
+#[inline(always)]
+pub fn fill_lane(src: &[u8], dst: &mut [u8], lane: usize, value: u8) {
+    fill_lane_4_wide!(src, dst, lane, value);
+}
+
+#[inline(always)]
+pub fn swizzle4_fill_inplace(src: &mut [u8], order: [u8; 4], lane: usize, value: u8) {
+    fill_and_swizzle_dyn_4_wide!(src, src, lane, value, order);
+}
+
+#[inline(always)]
+pub fn swizzle4_fill(src: &[u8], dst: &mut [u8], order: [u8; 4], lane: usize, value: u8) {
+    fill_and_swizzle_dyn_4_wide!(src, dst, lane, value, order);
+}
+
+#[inline(always)]
+pub fn swizzle4_inplace(src: &mut [u8], order: [u8; 4]) {
+    swizzle_dyn_4_wide!(src, src, order);
+}
+
+#[inline(always)]
+pub fn swizzle4(src: &[u8], dst: &mut [u8], order: [u8; 4]) {
+    swizzle_dyn_4_wide!(src, dst, order);
+}
+
+#[inline(always)]
+pub fn swizzle_inplace<const A: usize, const B: usize, const C: usize, const D: usize>(
+    src: &mut [u8],
+) {
+    const { assert!(A < 4 && B < 4 && C < 4 && D < 4, "channel index out of range") };
+    swizzle_order_4_wide!(src, src, Order<A, B, C, D>);
+}
+
+#[inline(always)]
+pub fn swizzle<const A: usize, const B: usize, const C: usize, const D: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+) {
+    const { assert!(A < 4 && B < 4 && C < 4 && D < 4, "channel index out of range") };
+    swizzle_order_4_wide!(src, dst, Order<A, B, C, D>);
+}
+
+crate::common::for_each_conversion!(impl_conversions);
+crate::common::for_each_expansion!(impl_expansions);
+crate::common::for_each_packing!(impl_packings);
+
+/// Swizzles of 16 bits per channel pixels.
+pub mod rgba16 {
+    use super::*;
+    use std::simd::{u16x16, u16x4};
+
+    crate::common::for_each_wide_conversion!(impl_wide_conversions {
+        u16x16, u16x4, u16, u16::MAX, swap;
+    });
+
+    #[inline(always)]
+    pub fn swap_bytes_inplace(src: &mut [u16]) {
+        swizzle_lanes_4_wide!(src, src, u16x16, u16x4, Lanes<0, 1, 2, 3, 4>, 0, true);
+    }
+
+    #[inline(always)]
+    pub fn swap_bytes(src: &[u16], dst: &mut [u16]) {
+        swizzle_lanes_4_wide!(src, dst, u16x16, u16x4, Lanes<0, 1, 2, 3, 4>, 0, true);
+    }
+
+    #[inline(always)]
+    pub fn from_u8(src: &[u8], dst: &mut [u16], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let [a, b, c, d] = order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array(order);
+
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let px: u16x16 = u8x16::from_slice(&src[i..i + VECTOR_WIDTH])
+                .swizzle_dyn(idxs)
+                .cast();
+            (px * u16x16::splat(257)).copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let px: u16x4 = u8x4::from_slice(&src[i..i + 4])
+                .swizzle_dyn(idxs_short)
+                .cast();
+            (px * u16x4::splat(257)).copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+
+    #[inline(always)]
+    pub fn to_u8(src: &[u16], dst: &mut [u8], order: [u8; 4], byte_order: crate::ByteOrder) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let swap = byte_order != crate::ByteOrder::NATIVE;
+        let [a, b, c, d] = order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array(order);
+
+        // Rounded division by 257 without a divide, `x + 128` does not fit in 16 bits.
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let px = u16x16::from_slice(&src[i..i + VECTOR_WIDTH]);
+            let px = if swap { px.swap_bytes() } else { px };
+            let px = px.cast::<u32>() + Simd::splat(128);
+            ((px - (px >> 8)) >> 8)
+                .cast::<u8>()
+                .swizzle_dyn(idxs)
+                .copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let px = u16x4::from_slice(&src[i..i + 4]);
+            let px = if swap { px.swap_bytes() } else { px };
+            let px = px.cast::<u32>() + Simd::splat(128);
+            ((px - (px >> 8)) >> 8)
+                .cast::<u8>()
+                .swizzle_dyn(idxs_short)
+                .copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+}
+
+/// Conversions between 4 channel and 16 bit 565 pixels.
+pub mod rgb565 {
+    use super::*;
+    use std::simd::{u16x8, u8x8};
+
+    /// 8 565 pixels.
+    const PIXELS: usize = 8;
+    const CONCAT_IDXS: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+    const INTERLEAVE_IDXS: [usize; 16] = [0, 8, 1, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15];
+
+    /// Interleaves 4 fields of 8 pixels, given as two vectors of two fields each, to 4 channel
+    /// pixels. `A`, `B`, `C` and `D` are the fields of the output channels.
+    struct Interleave<const A: usize, const B: usize, const C: usize, const D: usize>;
+
+    impl<const A: usize, const B: usize, const C: usize, const D: usize> Swizzle<32>
+        for Interleave<A, B, C, D>
+    {
+        const INDEX: [usize; 32] = {
+            let order = [A, B, C, D];
+            let mut idxs = [0; 32];
+            let mut i = 0;
+            while i < 32 {
+                idxs[i] = order[i % 4] * PIXELS + i / 4;
+                i += 1;
+            }
+            idxs
+        };
+    }
+
+    /// Takes channel `C` of 8 4 channel pixels.
+    struct Channel<const C: usize>;
+
+    impl<const C: usize> Swizzle<PIXELS> for Channel<C> {
+        const INDEX: [usize; PIXELS] = {
+            let mut idxs = [0; PIXELS];
+            let mut i = 0;
+            while i < PIXELS {
+                idxs[i] = i * 4 + C;
+                i += 1;
+            }
+            idxs
+        };
+    }
+
+    /// Repeats the high bits of a 5 or 6 bit channel in the low bits of its 8 bit value.
+    macro_rules! replicate {
+        ($c:expr, 5) => {
+            ($c << 3 | $c >> 2)
+        };
+        ($c:expr, 6) => {
+            ($c << 2 | $c >> 4)
+        };
+    }
+
+    /// Rounds the 8 bit channels of `$c` to the nearest 5 or 6 bit values without a divide.
+    macro_rules! round_to {
+        ($c:expr, 5) => {
+            ($c * u16x8::splat(249) + u16x8::splat(1014)) >> 11
+        };
+        ($c:expr, 6) => {
+            ($c * u16x8::splat(253) + u16x8::splat(505)) >> 10
+        };
+    }
+
+    macro_rules! unpack_565_4_wide {
+        ($src:expr, $dst:expr, $byte_order:expr, $a:literal, $b:literal, $c:literal, $d:literal) => {
+            assert!($src.len().is_multiple_of(2) && $src.len() * 2 == $dst.len());
+
+            let big = $byte_order == crate::ByteOrder::Big;
+            let blocks = $src.len() / (PIXELS * 2);
+            (0..blocks).for_each(|b| {
+                let (i, j) = (b * PIXELS * 2, b * PIXELS * 4);
+                let bytes = u8x16::from_slice(&$src[i..i + PIXELS * 2]);
+                let even: u8x8 = simd_swizzle!(bytes, [0, 2, 4, 6, 8, 10, 12, 14]);
+                let odd: u8x8 = simd_swizzle!(bytes, [1, 3, 5, 7, 9, 11, 13, 15]);
+                let (low, high) = if big { (odd, even) } else { (even, odd) };
+                let px = low.cast::<u16>() | high.cast::<u16>() << 8;
+
+                let (r, g, b) = (
+                    px >> 11,
+                    px >> 5 & u16x8::splat(0x3f),
+                    px & u16x8::splat(0x1f),
+                );
+                let first: u8x16 = simd_swizzle!(
+                    replicate!(r, 5).cast::<u8>(),
+                    replicate!(g, 6).cast::<u8>(),
+                    CONCAT_IDXS
+                );
+                let second: u8x16 =
+                    simd_swizzle!(replicate!(b, 5).cast::<u8>(), u8x8::splat(255), CONCAT_IDXS);
+                <Interleave<$a, $b, $c, $d> as Swizzle<32>>::concat_swizzle(first, second)
+                    .copy_to_slice(&mut $dst[j..j + PIXELS * 4]);
+            });
+
+            (blocks * PIXELS * 2..$src.len())
+                .step_by(2)
+                .zip((blocks * PIXELS * 4..).step_by(4))
+                .for_each(|(i, j)| {
+                    let bytes = [$src[i], $src[i + 1]];
+                    let px = if big {
+                        u16::from_be_bytes(bytes)
+                    } else {
+                        u16::from_le_bytes(bytes)
+                    };
+                    let (r, g, b) = (px >> 11, px >> 5 & 0x3f, px & 0x1f);
+                    let fields = [replicate!(r, 5), replicate!(g, 6), replicate!(b, 5), 255];
+                    $dst[j] = fields[$a] as u8;
+                    $dst[j + 1] = fields[$b] as u8;
+                    $dst[j + 2] = fields[$c] as u8;
+                    $dst[j + 3] = fields[$d] as u8;
+                });
+        };
+    }
+
+    macro_rules! pack_565_4_wide {
+        ($src:expr, $dst:expr, $byte_order:expr, $a:literal, $b:literal, $c:literal) => {
+            assert!($src.len().is_multiple_of(4) && $src.len() == $dst.len() * 2);
+
+            let big = $byte_order == crate::ByteOrder::Big;
+            let blocks = $src.len() / (PIXELS * 4);
+            (0..blocks).for_each(|b| {
+                let (i, j) = (b * PIXELS * 4, b * PIXELS * 2);
+                let px = Simd::<u8, { PIXELS * 4 }>::from_slice(&$src[i..i + PIXELS * 4]);
+                let r = <Channel<$a> as Swizzle<PIXELS>>::swizzle(px).cast::<u16>();
+                let g = <Channel<$b> as Swizzle<PIXELS>>::swizzle(px).cast::<u16>();
+                let b = <Channel<$c> as Swizzle<PIXELS>>::swizzle(px).cast::<u16>();
+                let px = round_to!(r, 5) << 11 | round_to!(g, 6) << 5 | round_to!(b, 5);
+
+                let (low, high) = (px.cast::<u8>(), (px >> 8).cast::<u8>());
+                let bytes: u8x16 = if big {
+                    simd_swizzle!(high, low, INTERLEAVE_IDXS)
+                } else {
+                    simd_swizzle!(low, high, INTERLEAVE_IDXS)
+                };
+                bytes.copy_to_slice(&mut $dst[j..j + PIXELS * 2]);
+            });
+
+            (blocks * PIXELS * 4..$src.len())
+                .step_by(4)
+                .zip((blocks * PIXELS * 2..).step_by(2))
+                .for_each(|(i, j)| {
+                    let (r, g, b) = (
+                        $src[i + $a] as u16,
+                        $src[i + $b] as u16,
+                        $src[i + $c] as u16,
+                    );
+                    let px = (r * 249 + 1014) >> 11 << 11
+                        | (g * 253 + 505) >> 10 << 5
+                        | (b * 249 + 1014) >> 11;
+                    let bytes = if big {
+                        px.to_be_bytes()
+                    } else {
+                        px.to_le_bytes()
+                    };
+                    $dst[j..j + 2].copy_from_slice(&bytes);
+                });
+        };
+    }
+
+    macro_rules! impl_565_unpackings {
+        ($($name:ident, $src:ident => $dst:ident, [$a:literal, $b:literal, $c:literal, $d:literal];)*) => {
+            $(
+                #[inline(always)]
+                pub fn $name(src: &[u8], dst: &mut [u8], byte_order: crate::ByteOrder) {
+                    unpack_565_4_wide!(src, dst, byte_order, $a, $b, $c, $d);
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_565_packings {
+        ($($name:ident, $src:ident => $dst:ident, [$a:literal, $b:literal, $c:literal];)*) => {
+            $(
+                #[inline(always)]
+                pub fn $name(src: &[u8], dst: &mut [u8], byte_order: crate::ByteOrder) {
+                    pack_565_4_wide!(src, dst, byte_order, $a, $b, $c);
+                }
+            )*
+        };
+    }
+
+    crate::common::for_each_565_unpacking!(impl_565_unpackings);
+    crate::common::for_each_565_packing!(impl_565_packings);
+}
+
+/// Swizzles of `f32` channel pixels.
+pub mod rgbaf32 {
+    use super::*;
+    use std::simd::num::SimdFloat;
+    use std::simd::{f32x16, f32x4, f32x8, StdFloat};
+
+    crate::common::for_each_wide_conversion!(impl_wide_conversions {
+        f32x8, f32x4, f32, 1.0, keep;
+    });
+
+    #[inline(always)]
+    pub fn from_u8(src: &[u8], dst: &mut [f32], order: [u8; 4]) {
+        from_u8_normalized(src, dst, order, [0.0; 4], [1.0; 4]);
+    }
+
+    #[inline(always)]
+    pub fn from_u8_normalized(
+        src: &[u8],
+        dst: &mut [f32],
+        order: [u8; 4],
+        mean: [f32; 4],
+        std: [f32; 4],
+    ) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let [a, b, c, d] = order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array(order);
+        let mean_wide = f32x16::from_array(std::array::from_fn(|i| mean[i % 4]));
+        let std_wide = f32x16::from_array(std::array::from_fn(|i| std[i % 4]));
+        let (mean, std) = (f32x4::from_array(mean), f32x4::from_array(std));
+
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let px: f32x16 = u8x16::from_slice(&src[i..i + VECTOR_WIDTH])
+                .swizzle_dyn(idxs)
+                .cast();
+            ((px / f32x16::splat(255.0) - mean_wide) / std_wide)
+                .copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let px: f32x4 = u8x4::from_slice(&src[i..i + 4])
+                .swizzle_dyn(idxs_short)
+                .cast();
+            ((px / f32x4::splat(255.0) - mean) / std).copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+
+    #[inline(always)]
+    pub fn to_u8(src: &[f32], dst: &mut [u8], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let [a, b, c, d] = order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array(order);
+
+        // The cast saturates and maps NaN to 0 like `as`.
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let px = f32x16::from_slice(&src[i..i + VECTOR_WIDTH])
+                .simd_clamp(f32x16::splat(0.0), f32x16::splat(1.0));
+            (px * f32x16::splat(255.0))
+                .round()
+                .cast::<u8>()
+                .swizzle_dyn(idxs)
+                .copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let px =
+                f32x4::from_slice(&src[i..i + 4]).simd_clamp(f32x4::splat(0.0), f32x4::splat(1.0));
+            (px * f32x4::splat(255.0))
+                .round()
+                .cast::<u8>()
+                .swizzle_dyn(idxs_short)
+                .copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+}
+
+/// Swizzles and conversions of half float channel pixels.
+pub mod rgbaf16 {
+    use super::*;
+    use std::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+    use std::simd::num::SimdFloat;
+    use std::simd::{f32x16, f32x4, u16x16, u16x4};
+    use std::simd::{Select, StdFloat, ToBytes};
+
+    crate::common::for_each_wide_conversion!(impl_wide_conversions {
+        u16x16, u16x4, u16, crate::rgbaf16::ONE, keep;
+    });
+
+    /// Byte indices that reorder the `u16` channels of `N / 8` pixels according to `order`.
+    fn byte_idxs<const N: usize>(order: [u8; 4]) -> Simd<u8, N> {
+        Simd::from_array(std::array::from_fn(|k| {
+            (k / 8 * 8 + order[k / 2 % 4] as usize * 2 + k % 2) as u8
+        }))
+    }
+
+    /// Vector version of [`crate::rgbaf16::f16_to_f32`].
+    #[inline(always)]
+    fn f16_to_f32<const N: usize>(bits: Simd<u16, N>) -> Simd<f32, N> {
+        let bits = bits.cast::<u32>();
+        let sign = (bits & Simd::splat(0x8000)) << 16;
+        let exp = bits >> 10 & Simd::splat(0x1f);
+        let man = bits & Simd::splat(0x3ff);
+
+        // Subnormals are the mantissa times 2^-24 which is exact, zeros come out the same way.
+        let subnormal = (man.cast::<f32>() * Simd::splat(1.0 / 16_777_216.0)).to_bits();
+        let special = Simd::splat(0x7f80_0000) | man << 13;
+        let normal = (exp + Simd::splat(112)) << 23 | man << 13;
+        let bits = exp.simd_eq(Simd::splat(0)).select(
+            subnormal,
+            exp.simd_eq(Simd::splat(0x1f)).select(special, normal),
+        );
+        Simd::from_bits(sign | bits)
+    }
+
+    /// Vector version of [`crate::rgbaf16::f32_to_f16`].
+    #[inline(always)]
+    fn f32_to_f16<const N: usize>(value: Simd<f32, N>) -> Simd<u16, N> {
+        let bits = value.to_bits();
+        let sign = bits >> 16 & Simd::splat(0x8000);
+        let abs = bits & Simd::splat(0x7fff_ffff);
+
+        let nan = Simd::splat(0x7e00) | (abs >> 13 & Simd::splat(0x3ff));
+        // Adding 0.5 rounds to a multiple of 2^-24, which leaves the subnormal half in the low
+        // mantissa bits.
+        let subnormal = (Simd::<f32, N>::from_bits(abs) + Simd::splat(0.5)).to_bits()
+            - Simd::splat(0x3f00_0000);
+        // Rebias the exponent and round the 13 dropped bits to even, a carry out of the
+        // mantissa increments the exponent and may reach infinity.
+        let odd = abs >> 13 & Simd::splat(1);
+        let normal = (abs - Simd::splat(112 << 23) + Simd::splat(0xfff) + odd) >> 13;
+        let half = abs.simd_gt(Simd::splat(0x7f80_0000)).select(
+            nan,
+            abs.simd_ge(Simd::splat(143 << 23)).select(
+                Simd::splat(0x7c00),
+                abs.simd_lt(Simd::splat(113 << 23)).select(subnormal, normal),
+            ),
+        );
+        (sign | half).cast()
+    }
+
+    #[inline(always)]
+    pub fn to_f32(src: &[u16], dst: &mut [f32], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let idxs = byte_idxs::<32>(order);
+        let idxs_short = byte_idxs::<8>(order);
+
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let bytes = u16x16::from_slice(&src[i..i + VECTOR_WIDTH]).to_ne_bytes();
+            f16_to_f32(u16x16::from_ne_bytes(bytes.swizzle_dyn(idxs)))
+                .copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let bytes = u16x4::from_slice(&src[i..i + 4]).to_ne_bytes();
+            f16_to_f32(u16x4::from_ne_bytes(bytes.swizzle_dyn(idxs_short)))
+                .copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+
+    #[inline(always)]
+    pub fn from_f32(src: &[f32], dst: &mut [u16], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let idxs = byte_idxs::<32>(order);
+        let idxs_short = byte_idxs::<8>(order);
+
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let bytes = f32_to_f16(f32x16::from_slice(&src[i..i + VECTOR_WIDTH])).to_ne_bytes();
+            u16x16::from_ne_bytes(bytes.swizzle_dyn(idxs))
+                .copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let bytes = f32_to_f16(f32x4::from_slice(&src[i..i + 4])).to_ne_bytes();
+            u16x4::from_ne_bytes(bytes.swizzle_dyn(idxs_short)).copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+
+    #[inline(always)]
+    pub fn to_u8(src: &[u16], dst: &mut [u8], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let [a, b, c, d] = order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array(order);
+
+        // The cast saturates and maps NaN to 0 like `as`.
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let px = f16_to_f32(u16x16::from_slice(&src[i..i + VECTOR_WIDTH]))
+                .simd_clamp(f32x16::splat(0.0), f32x16::splat(1.0));
+            (px * f32x16::splat(255.0))
+                .round()
+                .cast::<u8>()
+                .swizzle_dyn(idxs)
+                .copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let px = f16_to_f32(u16x4::from_slice(&src[i..i + 4]))
+                .simd_clamp(f32x4::splat(0.0), f32x4::splat(1.0));
+            (px * f32x4::splat(255.0))
+                .round()
+                .cast::<u8>()
+                .swizzle_dyn(idxs_short)
+                .copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
+
+    #[inline(always)]
+    pub fn from_u8(src: &[u8], dst: &mut [u16], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+
+        let [a, b, c, d] = order;
+        let idxs = u8x16::from_array(idx_order!(a, b, c, d));
+        let idxs_short = u8x4::from_array(order);
+
+        let end = (src.len() / VECTOR_WIDTH) * VECTOR_WIDTH;
+        (0..end).step_by(VECTOR_WIDTH).for_each(|i| {
+            let px: f32x16 = u8x16::from_slice(&src[i..i + VECTOR_WIDTH])
+                .swizzle_dyn(idxs)
+                .cast();
+            f32_to_f16(px / f32x16::splat(255.0)).copy_to_slice(&mut dst[i..i + VECTOR_WIDTH]);
+        });
+
+        (end..src.len()).step_by(4).for_each(|i| {
+            let px: f32x4 = u8x4::from_slice(&src[i..i + 4])
+                .swizzle_dyn(idxs_short)
+                .cast();
+            f32_to_f16(px / f32x4::splat(255.0)).copy_to_slice(&mut dst[i..i + 4]);
+        });
+    }
 }
 
 #[cfg(test)]
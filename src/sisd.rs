@@ -1,35 +1,41 @@
-const SERIAL_BATCH_WIDTH: usize = 16;
-
 macro_rules! swizzle_4_wide {
     ($src:expr, $dst:expr, $idxs:expr) => {
         assert!($src.len() % 4 == 0 && $src.len() == $dst.len() && $idxs.len() == 4);
 
-        let end = 0;
-        // TODO: Check if this is more performant
-        // let end = ($src.len() / SERIAL_BATCH_WIDTH) * SERIAL_BATCH_WIDTH;
-        // (0..end).step_by(SERIAL_BATCH_WIDTH).for_each(|i| {
-        //     let vec = &$src[i..i + SERIAL_BATCH_WIDTH];
-        //     #[rustfmt::skip]
-        //     let swizzled = &[ // Hardcoded SERIAL_BATCH_WIDTH = 16
-        //         vec[$idxs[0]], vec[$idxs[1]], vec[$idxs[2]], vec[$idxs[3]],
-        //         vec[$idxs[0] + 4], vec[$idxs[1] + 4], vec[$idxs[2] + 4], vec[$idxs[3] + 4],
-        //         vec[$idxs[0] + 8], vec[$idxs[1] + 8], vec[$idxs[2] + 8], vec[$idxs[3] + 8],
-        //         vec[$idxs[0] + 12], vec[$idxs[1] + 12], vec[$idxs[2] + 12], vec[$idxs[3] + 12],
-        //     ];
-        //     $dst[i..i + SERIAL_BATCH_WIDTH].copy_from_slice(swizzled);
-        // });
-
-        (end..$src.len()).step_by(4).for_each(|i| {
-            let (a, b, c, d) = ($src[i + 0], $src[i + 1], $src[i + 2], $src[i + 3]);
-            $dst[i + $idxs[0]] = a;
-            $dst[i + $idxs[1]] = b;
-            $dst[i + $idxs[2]] = c;
-            $dst[i + $idxs[3]] = d;
+        (0..$src.len()).step_by(4).for_each(|i| {
+            let (a, b, c, d) = (
+                $src[i + $idxs[0] as usize],
+                $src[i + $idxs[1] as usize],
+                $src[i + $idxs[2] as usize],
+                $src[i + $idxs[3] as usize],
+            );
+            $dst[i] = a;
+            $dst[i + 1] = b;
+            $dst[i + 2] = c;
+            $dst[i + 3] = d;
+        });
+    };
+}
+
+macro_rules! swap_rb_3_wide {
+    ($src:expr, $dst:expr) => {
+        assert!($src.len() % 3 == 0 && $src.len() == $dst.len());
+        (0..$src.len()).step_by(3).for_each(|i| {
+            let (r, g, b) = ($src[i], $src[i + 1], $src[i + 2]);
+            $dst[i] = b;
+            $dst[i + 1] = g;
+            $dst[i + 2] = r;
         });
     };
 }
 
 macro_rules! change_alpha_fourth {
+    ($src:expr, $to:expr) => {
+        assert!($src.len() % 4 == 0);
+        (0..$src.len()).step_by(4).for_each(|i| {
+            $src[i + 3] = $to;
+        })
+    };
     ($src:expr, $dst:expr, $to:expr) => {
         assert!($src.len() % 4 == 0 && $src.len() == $dst.len());
         (0..$src.len()).step_by(4).for_each(|i| {
@@ -54,6 +60,191 @@ macro_rules! change_alpha_fourth_and_swizzle {
     };
 }
 
+macro_rules! fill_lane_4_wide {
+    ($src:expr, $lane:expr, $to:expr) => {
+        assert!($src.len() % 4 == 0 && $lane < 4);
+        (0..$src.len()).step_by(4).for_each(|i| {
+            $src[i + $lane] = $to;
+        })
+    };
+    ($src:expr, $dst:expr, $lane:expr, $to:expr) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len() && $lane < 4);
+        (0..$src.len()).step_by(4).for_each(|i| {
+            $dst[i..i + 4].copy_from_slice(&$src[i..i + 4]);
+            $dst[i + $lane] = $to;
+        })
+    };
+}
+
+macro_rules! fill_and_swizzle_4_wide {
+    ($src:expr, $dst:expr, $lane:expr, $to:expr, $idxs:expr) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len() && $idxs.len() == 4);
+        assert!($lane < 4);
+        (0..$src.len()).step_by(4).for_each(|i| {
+            let mut px = [$src[i], $src[i + 1], $src[i + 2], $src[i + 3]];
+            px[$lane] = $to;
+            $dst[i] = px[$idxs[0] as usize];
+            $dst[i + 1] = px[$idxs[1] as usize];
+            $dst[i + 2] = px[$idxs[2] as usize];
+            $dst[i + 3] = px[$idxs[3] as usize];
+        })
+    };
+}
+
+macro_rules! expand_3_to_4 {
+    ($src:expr, $dst:expr, $fill:expr, $idxs:expr) => {
+        assert!($src.len() % 3 == 0 && $src.len() / 3 * 4 == $dst.len());
+        (0..$src.len() / 3).for_each(|p| {
+            let (i, j) = (p * 3, p * 4);
+            let px = [$src[i], $src[i + 1], $src[i + 2], $fill];
+            $dst[j] = px[$idxs[0]];
+            $dst[j + 1] = px[$idxs[1]];
+            $dst[j + 2] = px[$idxs[2]];
+            $dst[j + 3] = px[$idxs[3]];
+        })
+    };
+}
+
+macro_rules! pack_4_to_3 {
+    ($src:expr, $dst:expr, $idxs:expr) => {
+        assert!($src.len() % 4 == 0 && $src.len() / 4 * 3 == $dst.len());
+        (0..$src.len() / 4).for_each(|p| {
+            let (i, j) = (p * 4, p * 3);
+            let (a, b, c) = (
+                $src[i + $idxs[0]],
+                $src[i + $idxs[1]],
+                $src[i + $idxs[2]],
+            );
+            $dst[j] = a;
+            $dst[j + 1] = b;
+            $dst[j + 2] = c;
+        })
+    };
+}
+
+macro_rules! fill_lane {
+    () => {
+        None::<usize>
+    };
+    ($lane:literal) => {
+        Some($lane)
+    };
+}
+
+macro_rules! swizzle_lanes_4_wide {
+    ($src:expr, $dst:expr, $idxs:expr, $lane:expr, $to:expr $(, $swap:expr)?) => {
+        assert!($src.len() % 4 == 0 && $src.len() == $dst.len() && $idxs.len() == 4);
+        (0..$src.len()).step_by(4).for_each(|i| {
+            let mut px = [$src[i], $src[i + 1], $src[i + 2], $src[i + 3]];
+            $(if $swap {
+                px = px.map(|c| c.swap_bytes());
+            })?
+            if let Some(lane) = $lane {
+                px[lane] = $to;
+            }
+            $dst[i] = px[$idxs[0]];
+            $dst[i + 1] = px[$idxs[1]];
+            $dst[i + 2] = px[$idxs[2]];
+            $dst[i + 3] = px[$idxs[3]];
+        })
+    };
+}
+
+/// Implements [`crate::common::for_each_wide_conversion`] for `$elem` channels. With `swap` the
+/// functions take the byte order of the source, with `keep` the source is in native endian.
+macro_rules! impl_wide_conversions {
+    (
+        $elem:ty, $fill:expr, $swap:ident;
+        $($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*
+    ) => {
+        $(
+            impl_wide_conversions!(
+                @fn $swap, $elem, $fill, $name, $inplace, [$($order),*], fill_lane!($($lane)?)
+            );
+        )*
+    };
+    (@fn swap, $elem:ty, $fill:expr, $name:ident, $inplace:ident, $idxs:expr, $lane:expr) => {
+        #[inline(always)]
+        pub fn $inplace(src: &mut [$elem], byte_order: crate::ByteOrder) {
+            let swap = byte_order != crate::ByteOrder::NATIVE;
+            swizzle_lanes_4_wide!(src, src, $idxs, $lane, $fill, swap);
+        }
+
+        #[inline(always)]
+        pub fn $name(src: &[$elem], dst: &mut [$elem], byte_order: crate::ByteOrder) {
+            let swap = byte_order != crate::ByteOrder::NATIVE;
+            swizzle_lanes_4_wide!(src, dst, $idxs, $lane, $fill, swap);
+        }
+    };
+    (@fn keep, $elem:ty, $fill:expr, $name:ident, $inplace:ident, $idxs:expr, $lane:expr) => {
+        #[inline(always)]
+        pub fn $inplace(src: &mut [$elem]) {
+            swizzle_lanes_4_wide!(src, src, $idxs, $lane, $fill);
+        }
+
+        #[inline(always)]
+        pub fn $name(src: &[$elem], dst: &mut [$elem]) {
+            swizzle_lanes_4_wide!(src, dst, $idxs, $lane, $fill);
+        }
+    };
+}
+
+macro_rules! impl_packings {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+        $(
+            #[inline(always)]
+            pub fn $name(src: &[u8], dst: &mut [u8]) {
+                pack_4_to_3!(src, dst, [$($order),*]);
+            }
+        )*
+    };
+}
+
+macro_rules! impl_expansions {
+    ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $alpha:ident)?;)*) => {
+        $(
+            impl_expansions!(@fn $name, [$($order),*] $(, $alpha)?);
+        )*
+    };
+    (@fn $name:ident, $idxs:expr, alpha) => {
+        #[inline(always)]
+        pub fn $name(src: &[u8], dst: &mut [u8], alpha: u8) {
+            expand_3_to_4!(src, dst, alpha, $idxs);
+        }
+    };
+    (@fn $name:ident, $idxs:expr) => {
+        #[inline(always)]
+        pub fn $name(src: &[u8], dst: &mut [u8]) {
+            expand_3_to_4!(src, dst, 255, $idxs);
+        }
+    };
+}
+
+macro_rules! convert_4_wide {
+    ($src:expr, $dst:expr, $idxs:expr) => {
+        swizzle_4_wide!($src, $dst, $idxs);
+    };
+    ($src:expr, $dst:expr, $idxs:expr, $lane:literal) => {
+        fill_and_swizzle_4_wide!($src, $dst, $lane, 255, $idxs);
+    };
+}
+
+macro_rules! impl_conversions {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?;)*) => {
+        $(
+            #[inline(always)]
+            pub fn $inplace(src: &mut [u8]) {
+                convert_4_wide!(src, src, [$($order),*] $(, $lane)?);
+            }
+
+            #[inline(always)]
+            pub fn $name(src: &[u8], dst: &mut [u8]) {
+                convert_4_wide!(src, dst, [$($order),*] $(, $lane)?);
+            }
+        )*
+    };
+}
+
 #[inline(always)]
 pub fn rgba_to_bgra_inplace(src: &mut [u8]) {
     swizzle_4_wide!(src, src, [2, 1, 0, 3]);
@@ -76,6 +267,24 @@ pub fn rgba32_to_bgra_inplace(src: &mut [u8]) {
 pub fn rgba32_to_rgba_inplace(src: &mut [u8]) {
     #[cfg(target_endian = "little")]
     swizzle_4_wide!(src, src, [3, 2, 1, 0]);
+    #[cfg(target_endian = "big")]
+    assert!(src.len().is_multiple_of(4));
+}
+
+#[inline(always)]
+pub fn rgba32_to_bgra(src: &[u8], dst: &mut [u8]) {
+    #[cfg(target_endian = "little")]
+    swizzle_4_wide!(src, dst, [1, 2, 3, 0]);
+    #[cfg(target_endian = "big")]
+    swizzle_4_wide!(src, dst, [2, 1, 0, 3]);
+}
+
+#[inline(always)]
+pub fn rgba32_to_rgba(src: &[u8], dst: &mut [u8]) {
+    #[cfg(target_endian = "little")]
+    swizzle_4_wide!(src, dst, [3, 2, 1, 0]);
+    #[cfg(target_endian = "big")]
+    swizzle_4_wide!(src, dst, [0, 1, 2, 3]);
 }
 
 #[inline(always)]
@@ -90,7 +299,7 @@ pub fn bgra_to_rgba(src: &[u8], dst: &mut [u8]) {
 
 #[inline(always)]
 pub fn rgb0_to_rgbx_inplace(src: &mut [u8]) {
-    change_alpha_fourth!(src, src, 255);
+    change_alpha_fourth!(src, 255);
 }
 
 #[inline(always)]
@@ -100,7 +309,7 @@ pub fn rgb0_to_rgbx(src: &[u8], dst: &mut [u8]) {
 
 #[inline(always)]
 pub fn bgr0_to_bgrx_inplace(src: &mut [u8]) {
-    change_alpha_fourth!(src, src, 255);
+    change_alpha_fourth!(src, 255);
 }
 
 #[inline(always)]
@@ -129,13 +338,301 @@ pub fn bgr0_to_rgbx(src: &[u8], dst: &mut [u8]) {
 }
 
 #[inline(always)]
-pub fn argb_to_rgba_inplace(src: &mut [u8]) {
-    swizzle_4_wide!(src, src, [1, 2, 3, 0]);
+pub fn rgb24_to_bgr24_inplace(src: &mut [u8]) {
+    swap_rb_3_wide!(src, src);
+}
+
+#[inline(always)]
+pub fn rgb24_to_bgr24(src: &[u8], dst: &mut [u8]) {
+    swap_rb_3_wide!(src, dst);
+}
+
+#[inline(always)]
+pub fn bgr24_to_rgb24_inplace(src: &mut [u8]) {
+    swap_rb_3_wide!(src, src);
+}
+
+#[inline(always)]
+pub fn bgr24_to_rgb24(src: &[u8], dst: &mut [u8]) {
+    swap_rb_3_wide!(src, dst);
+}
+
+#[inline(always)]
+pub fn fill_lane_inplace(src: &mut [u8], lane: usize, value: u8) {
+    fill_lane_4_wide!(src, lane, value);
+}
+
+#[inline(always)]
+pub fn fill_lane(src: &[u8], dst: &mut [u8], lane: usize, value: u8) {
+    fill_lane_4_wide!(src, dst, lane, value);
+}
+
+#[inline(always)]
+pub fn swizzle4_fill_inplace(src: &mut [u8], order: [u8; 4], lane: usize, value: u8) {
+    assert!(order.iter().all(|&i| i < 4));
+    fill_and_swizzle_4_wide!(src, src, lane, value, order);
+}
+
+#[inline(always)]
+pub fn swizzle4_fill(src: &[u8], dst: &mut [u8], order: [u8; 4], lane: usize, value: u8) {
+    assert!(order.iter().all(|&i| i < 4));
+    fill_and_swizzle_4_wide!(src, dst, lane, value, order);
+}
+
+#[inline(always)]
+pub fn swizzle4_inplace(src: &mut [u8], order: [u8; 4]) {
+    assert!(order.iter().all(|&i| i < 4));
+    swizzle_4_wide!(src, src, order);
+}
+
+#[inline(always)]
+pub fn swizzle4(src: &[u8], dst: &mut [u8], order: [u8; 4]) {
+    assert!(order.iter().all(|&i| i < 4));
+    swizzle_4_wide!(src, dst, order);
+}
+
+#[inline(always)]
+pub fn swizzle_inplace<const A: usize, const B: usize, const C: usize, const D: usize>(
+    src: &mut [u8],
+) {
+    const { assert!(A < 4 && B < 4 && C < 4 && D < 4, "channel index out of range") };
+    swizzle_4_wide!(src, src, [A, B, C, D]);
+}
+
+#[inline(always)]
+pub fn swizzle<const A: usize, const B: usize, const C: usize, const D: usize>(
+    src: &[u8],
+    dst: &mut [u8],
+) {
+    const { assert!(A < 4 && B < 4 && C < 4 && D < 4, "channel index out of range") };
+    swizzle_4_wide!(src, dst, [A, B, C, D]);
+}
+
+crate::common::for_each_conversion!(impl_conversions);
+crate::common::for_each_expansion!(impl_expansions);
+crate::common::for_each_packing!(impl_packings);
+
+/// Swizzles of 16 bits per channel pixels.
+pub mod rgba16 {
+    crate::common::for_each_wide_conversion!(impl_wide_conversions {
+        u16, u16::MAX, swap;
+    });
+
+    #[inline(always)]
+    pub fn swap_bytes_inplace(src: &mut [u16]) {
+        assert!(src.len().is_multiple_of(4));
+        src.iter_mut().for_each(|c| *c = c.swap_bytes());
+    }
+
+    #[inline(always)]
+    pub fn swap_bytes(src: &[u16], dst: &mut [u16]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        dst.iter_mut()
+            .zip(src)
+            .for_each(|(d, s)| *d = s.swap_bytes());
+    }
+
+    #[inline(always)]
+    pub fn from_u8(src: &[u8], dst: &mut [u16], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+        (0..src.len()).step_by(4).for_each(|i| {
+            for c in 0..4 {
+                dst[i + c] = src[i + order[c] as usize] as u16 * 257;
+            }
+        })
+    }
+
+    #[inline(always)]
+    pub fn to_u8(src: &[u16], dst: &mut [u8], order: [u8; 4], byte_order: crate::ByteOrder) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+        let swap = byte_order != crate::ByteOrder::NATIVE;
+        (0..src.len()).step_by(4).for_each(|i| {
+            for c in 0..4 {
+                let value = src[i + order[c] as usize];
+                let value = if swap { value.swap_bytes() } else { value };
+                dst[i + c] = ((value as u32 + 128) / 257) as u8;
+            }
+        })
+    }
+}
+
+/// Conversions between 4 channel and 16 bit 565 pixels.
+pub mod rgb565 {
+    macro_rules! unpack_565 {
+        ($src:expr, $dst:expr, $byte_order:expr, $idxs:expr) => {
+            assert!($src.len().is_multiple_of(2) && $src.len() * 2 == $dst.len());
+            let big = $byte_order == crate::ByteOrder::Big;
+            (0..$src.len() / 2).for_each(|p| {
+                let (i, j) = (p * 2, p * 4);
+                let bytes = [$src[i], $src[i + 1]];
+                let px = if big {
+                    u16::from_be_bytes(bytes)
+                } else {
+                    u16::from_le_bytes(bytes)
+                };
+                let (r, g, b) = (px >> 11, px >> 5 & 0x3f, px & 0x1f);
+                let fields = [r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2, 255];
+                $dst[j] = fields[$idxs[0]] as u8;
+                $dst[j + 1] = fields[$idxs[1]] as u8;
+                $dst[j + 2] = fields[$idxs[2]] as u8;
+                $dst[j + 3] = fields[$idxs[3]] as u8;
+            })
+        };
+    }
+
+    macro_rules! pack_565 {
+        ($src:expr, $dst:expr, $byte_order:expr, $idxs:expr) => {
+            assert!($src.len().is_multiple_of(4) && $src.len() == $dst.len() * 2);
+            let big = $byte_order == crate::ByteOrder::Big;
+            (0..$src.len() / 4).for_each(|p| {
+                let (i, j) = (p * 4, p * 2);
+                let (r, g, b) = (
+                    $src[i + $idxs[0]] as u16,
+                    $src[i + $idxs[1]] as u16,
+                    $src[i + $idxs[2]] as u16,
+                );
+                // Rounds to the nearest 5 and 6 bit values without a divide.
+                let px = (r * 249 + 1014) >> 11 << 11
+                    | (g * 253 + 505) >> 10 << 5
+                    | (b * 249 + 1014) >> 11;
+                let bytes = if big {
+                    px.to_be_bytes()
+                } else {
+                    px.to_le_bytes()
+                };
+                $dst[j..j + 2].copy_from_slice(&bytes);
+            })
+        };
+    }
+
+    macro_rules! impl_565_unpackings {
+        ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+            $(
+                #[inline(always)]
+                pub fn $name(src: &[u8], dst: &mut [u8], byte_order: crate::ByteOrder) {
+                    unpack_565!(src, dst, byte_order, [$($order),*]);
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_565_packings {
+        ($($name:ident, $src:ident => $dst:ident, [$($order:literal),*];)*) => {
+            $(
+                #[inline(always)]
+                pub fn $name(src: &[u8], dst: &mut [u8], byte_order: crate::ByteOrder) {
+                    pack_565!(src, dst, byte_order, [$($order),*]);
+                }
+            )*
+        };
+    }
+
+    crate::common::for_each_565_unpacking!(impl_565_unpackings);
+    crate::common::for_each_565_packing!(impl_565_packings);
+}
+
+/// Swizzles of `f32` channel pixels.
+pub mod rgbaf32 {
+    crate::common::for_each_wide_conversion!(impl_wide_conversions {
+        f32, 1.0, keep;
+    });
+
+    #[inline(always)]
+    pub fn from_u8(src: &[u8], dst: &mut [f32], order: [u8; 4]) {
+        from_u8_normalized(src, dst, order, [0.0; 4], [1.0; 4]);
+    }
+
+    #[inline(always)]
+    pub fn from_u8_normalized(
+        src: &[u8],
+        dst: &mut [f32],
+        order: [u8; 4],
+        mean: [f32; 4],
+        std: [f32; 4],
+    ) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+        (0..src.len()).step_by(4).for_each(|i| {
+            for c in 0..4 {
+                let value = src[i + order[c] as usize] as f32;
+                dst[i + c] = (value / 255.0 - mean[c]) / std[c];
+            }
+        })
+    }
+
+    #[inline(always)]
+    pub fn to_u8(src: &[f32], dst: &mut [u8], order: [u8; 4]) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+        (0..src.len()).step_by(4).for_each(|i| {
+            for c in 0..4 {
+                let value = src[i + order[c] as usize].clamp(0.0, 1.0);
+                dst[i + c] = (value * 255.0).round() as u8;
+            }
+        })
+    }
+}
+
+/// Swizzles and conversions of half float channel pixels.
+pub mod rgbaf16 {
+    use crate::rgbaf16::{f16_to_f32, f32_to_f16};
+
+    crate::common::for_each_wide_conversion!(impl_wide_conversions {
+        u16, crate::rgbaf16::ONE, keep;
+    });
+
+    /// The half float nearest to every `u8` divided by 255.
+    const U8_TO_F16: [u16; 256] = {
+        let mut lut = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            lut[i] = f32_to_f16(i as f32 / 255.0);
+            i += 1;
+        }
+        lut
+    };
+
+    /// Converts every pixel of `src` to `dst` with `f`, reordering the channels according to
+    /// `order`.
+    #[inline(always)]
+    fn map_4<S: Copy, D>(src: &[S], dst: &mut [D], order: [u8; 4], f: impl Fn(S) -> D) {
+        assert!(src.len().is_multiple_of(4) && src.len() == dst.len());
+        assert!(order.iter().all(|&i| i < 4));
+        (0..src.len()).step_by(4).for_each(|i| {
+            for c in 0..4 {
+                dst[i + c] = f(src[i + order[c] as usize]);
+            }
+        })
+    }
+
+    #[inline(always)]
+    pub fn to_f32(src: &[u16], dst: &mut [f32], order: [u8; 4]) {
+        map_4(src, dst, order, f16_to_f32);
+    }
+
+    #[inline(always)]
+    pub fn from_f32(src: &[f32], dst: &mut [u16], order: [u8; 4]) {
+        map_4(src, dst, order, f32_to_f16);
+    }
+
+    #[inline(always)]
+    pub fn to_u8(src: &[u16], dst: &mut [u8], order: [u8; 4]) {
+        map_4(src, dst, order, |c| {
+            (f16_to_f32(c).clamp(0.0, 1.0) * 255.0).round() as u8
+        });
+    }
+
+    #[inline(always)]
+    pub fn from_u8(src: &[u8], dst: &mut [u16], order: [u8; 4]) {
+        map_4(src, dst, order, |c| U8_TO_F16[c as usize]);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    crate::common::impl_tests!{}
+    crate::common::impl_tests! {}
 }
@@ -0,0 +1,269 @@
+//! Conversions of images whose rows are padded, i.e. where the distance in bytes between the
+//! start of two rows (the stride) is larger than `width * 4`.
+//!
+//! Only the first `width * 4` bytes of every row are converted, padding bytes at the end of
+//! the rows in the destination are left untouched.
+
+use crate::error::check_rows;
+use crate::SwizzleError;
+
+#[inline(always)]
+pub(crate) fn for_each_row(
+    src: &[u8],
+    src_stride: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    row_len: usize,
+    height: usize,
+    mut f: impl FnMut(&[u8], &mut [u8]),
+) -> Result<(), SwizzleError> {
+    check_rows(src.len(), src_stride, row_len, height)?;
+    check_rows(dst.len(), dst_stride, row_len, height)?;
+
+    (0..height).for_each(|y| {
+        let (s, d) = (y * src_stride, y * dst_stride);
+        f(&src[s..s + row_len], &mut dst[d..d + row_len]);
+    });
+    Ok(())
+}
+
+#[inline(always)]
+pub(crate) fn for_each_row_inplace(
+    src: &mut [u8],
+    stride: usize,
+    row_len: usize,
+    height: usize,
+    mut f: impl FnMut(&mut [u8]),
+) -> Result<(), SwizzleError> {
+    check_rows(src.len(), stride, row_len, height)?;
+
+    (0..height).for_each(|y| {
+        let s = y * stride;
+        f(&mut src[s..s + row_len]);
+    });
+    Ok(())
+}
+
+macro_rules! impl_strided {
+    ($($strided_name:ident => $name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Strided version of [`", stringify!($name), "`](crate::", stringify!($name), ").")]
+            ///
+            /// Converts `height` rows of `width` pixels where rows start every `src_stride` bytes
+            /// in `src` and every `dst_stride` bytes in `dst`.
+            ///
+            /// Returns an error if a stride is smaller than `width * 4` or if a buffer is too
+            /// small for the image.
+            #[inline]
+            pub fn $strided_name(
+                src: &[u8],
+                src_stride: usize,
+                dst: &mut [u8],
+                dst_stride: usize,
+                width: usize,
+                height: usize,
+            ) -> Result<(), SwizzleError> {
+                for_each_row(
+                    src,
+                    src_stride,
+                    dst,
+                    dst_stride,
+                    width.saturating_mul(4),
+                    height,
+                    crate::$name,
+                )
+            }
+        )*
+    };
+}
+
+macro_rules! impl_strided_inplace {
+    ($($strided_name:ident => $name:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Strided version of [`", stringify!($name), "`](crate::", stringify!($name), ").")]
+            ///
+            /// Converts `height` rows of `width` pixels where rows start every `stride` bytes.
+            ///
+            /// Returns an error if `stride` is smaller than `width * 4` or if `src` is too small
+            /// for the image.
+            #[inline]
+            pub fn $strided_name(
+                src: &mut [u8],
+                stride: usize,
+                width: usize,
+                height: usize,
+            ) -> Result<(), SwizzleError> {
+                for_each_row_inplace(src, stride, width.saturating_mul(4), height, crate::$name)
+            }
+        )*
+    };
+}
+
+impl_strided! {
+    rgba_to_bgra_strided => rgba_to_bgra,
+    rgba32_to_bgra_strided => rgba32_to_bgra,
+    rgba32_to_rgba_strided => rgba32_to_rgba,
+    bgra_to_rgba_strided => bgra_to_rgba,
+    rgb0_to_rgbx_strided => rgb0_to_rgbx,
+    bgr0_to_bgrx_strided => bgr0_to_bgrx,
+    rgb0_to_bgrx_strided => rgb0_to_bgrx,
+    bgr0_to_rgbx_strided => bgr0_to_rgbx,
+}
+
+impl_strided_inplace! {
+    rgba_to_bgra_inplace_strided => rgba_to_bgra_inplace,
+    rgba32_to_bgra_inplace_strided => rgba32_to_bgra_inplace,
+    rgba32_to_rgba_inplace_strided => rgba32_to_rgba_inplace,
+    bgra_to_rgba_inplace_strided => bgra_to_rgba_inplace,
+    rgb0_to_rgbx_inplace_strided => rgb0_to_rgbx_inplace,
+    bgr0_to_bgrx_inplace_strided => bgr0_to_bgrx_inplace,
+    rgb0_to_bgrx_inplace_strided => rgb0_to_bgrx_inplace,
+    bgr0_to_rgbx_inplace_strided => bgr0_to_rgbx_inplace,
+}
+
+macro_rules! impl_strided_conversions {
+    ($($name:ident, $inplace:ident, $src:ident => $dst:ident, [$($order:literal),*] $(, $lane:literal)?
+        => $try_name:ident, $try_inplace:ident, $strided:ident, $strided_inplace:ident;)*) => {
+        impl_strided! { $($strided => $name),* }
+        impl_strided_inplace! { $($strided_inplace => $inplace),* }
+    };
+}
+
+crate::common::for_each_conversion!(@variants impl_strided_conversions);
+
+/// Strided version of [`swizzle4`](crate::swizzle4).
+///
+/// Returns an error if a stride is smaller than `width * 4`, if a buffer is too small for the
+/// image or if any index in `order` is greater than 3.
+#[inline]
+pub fn swizzle4_strided(
+    src: &[u8],
+    src_stride: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    order: [u8; 4],
+) -> Result<(), SwizzleError> {
+    crate::error::check_order(order)?;
+    for_each_row(
+        src,
+        src_stride,
+        dst,
+        dst_stride,
+        width.saturating_mul(4),
+        height,
+        |src, dst| crate::swizzle4(src, dst, order),
+    )
+}
+
+/// Strided version of [`swizzle4_inplace`](crate::swizzle4_inplace).
+///
+/// Returns an error if `stride` is smaller than `width * 4`, if `src` is too small for the
+/// image or if any index in `order` is greater than 3.
+#[inline]
+pub fn swizzle4_inplace_strided(
+    src: &mut [u8],
+    stride: usize,
+    width: usize,
+    height: usize,
+    order: [u8; 4],
+) -> Result<(), SwizzleError> {
+    crate::error::check_order(order)?;
+    for_each_row_inplace(src, stride, width.saturating_mul(4), height, |src| {
+        crate::swizzle4_inplace(src, order)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAD: u8 = 0xAA;
+
+    fn padded_image(width: usize, height: usize, stride: usize, px: [u8; 4]) -> Vec<u8> {
+        let mut img = vec![PAD; stride * height];
+        img.chunks_exact_mut(stride).for_each(|row| {
+            row[..width * 4]
+                .chunks_exact_mut(4)
+                .for_each(|p| p.copy_from_slice(&px));
+        });
+        img
+    }
+
+    #[test]
+    fn test_rgba_to_bgra_strided() {
+        let (width, height) = (21, 5);
+        let src = padded_image(width, height, width * 4 + 12, [1, 2, 3, 4]);
+        let mut dst = vec![PAD; (width * 4 + 4) * height];
+        rgba_to_bgra_strided(&src, width * 4 + 12, &mut dst, width * 4 + 4, width, height)
+            .unwrap();
+        assert_eq!(dst, padded_image(width, height, width * 4 + 4, [3, 2, 1, 4]));
+    }
+
+    #[test]
+    fn test_rgb0_to_rgbx_inplace_strided() {
+        let (width, height) = (9, 3);
+        let stride = width * 4 + 8;
+        let mut img = padded_image(width, height, stride, [1, 2, 3, 0]);
+        rgb0_to_rgbx_inplace_strided(&mut img, stride, width, height).unwrap();
+        assert_eq!(img, padded_image(width, height, stride, [1, 2, 3, 255]));
+    }
+
+    #[test]
+    fn test_xbgr_to_rgb0_strided() {
+        let (width, height) = (13, 2);
+        let src = padded_image(width, height, width * 4 + 4, [9, 3, 2, 1]);
+        let mut dst = vec![PAD; width * 4 * height];
+        xbgr_to_rgb0_strided(&src, width * 4 + 4, &mut dst, width * 4, width, height).unwrap();
+        assert_eq!(dst, padded_image(width, height, width * 4, [1, 2, 3, 255]));
+
+        let mut img = src.clone();
+        xbgr_to_rgb0_inplace_strided(&mut img, width * 4 + 4, width, height).unwrap();
+        assert_eq!(img, padded_image(width, height, width * 4 + 4, [1, 2, 3, 255]));
+    }
+
+    #[test]
+    fn test_swizzle4_strided() {
+        let (width, height) = (17, 4);
+        let src = padded_image(width, height, width * 4, [1, 2, 3, 4]);
+        let mut dst = vec![PAD; (width * 4 + 4) * height];
+        swizzle4_strided(&src, width * 4, &mut dst, width * 4 + 4, width, height, [3, 0, 1, 2])
+            .unwrap();
+        assert_eq!(dst, padded_image(width, height, width * 4 + 4, [4, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_last_row_without_padding() {
+        let (width, height, stride) = (3, 2, 16);
+        let mut img = padded_image(width, height, stride, [1, 2, 3, 4]);
+        img.truncate(stride + width * 4);
+        bgra_to_rgba_inplace_strided(&mut img, stride, width, height).unwrap();
+        let mut correct = padded_image(width, height, stride, [3, 2, 1, 4]);
+        correct.truncate(stride + width * 4);
+        assert_eq!(img, correct);
+    }
+
+    #[test]
+    fn test_strided_errors() {
+        let src = vec![0; 64];
+        let mut dst = vec![0; 64];
+        assert_eq!(
+            rgba_to_bgra_strided(&src, 12, &mut dst, 16, 4, 4),
+            Err(SwizzleError::StrideTooSmall { stride: 12, min: 16 })
+        );
+        assert_eq!(
+            rgba_to_bgra_strided(&src, 20, &mut dst, 16, 4, 4),
+            Err(SwizzleError::BufferTooSmall { len: 64, min: 76 })
+        );
+        assert_eq!(
+            rgba_to_bgra_inplace_strided(&mut dst, 16, 4, 5),
+            Err(SwizzleError::BufferTooSmall { len: 64, min: 80 })
+        );
+        assert_eq!(
+            swizzle4_inplace_strided(&mut dst, 16, 4, 4, [4, 0, 0, 0]),
+            Err(SwizzleError::InvalidOrder { order: [4, 0, 0, 0] })
+        );
+        assert_eq!(rgba_to_bgra_strided(&[], 16, &mut [], 16, 4, 0), Ok(()));
+    }
+}